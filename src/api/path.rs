@@ -1,9 +1,77 @@
 //! The path module provides utilities for working with file and directory paths.
 //! 
 
-use std::path::PathBuf;
+use futures::future;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
 use wasm_bindgen::JsValue;
 
+/// A well-known directory that relative paths can be resolved against.
+///
+/// Accepted by every API taking a base directory, such as the `fs` and `dialog` plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum BaseDirectory {
+    Audio = 1,
+    Cache = 2,
+    Config = 3,
+    Data = 4,
+    LocalData = 5,
+    Document = 6,
+    Download = 7,
+    Picture = 8,
+    Public = 9,
+    Video = 10,
+    Resource = 11,
+    Temp = 12,
+    AppConfig = 13,
+    AppData = 14,
+    AppLocalData = 15,
+    AppCache = 16,
+    AppLog = 17,
+    Desktop = 18,
+    Executable = 19,
+    Font = 20,
+    Home = 21,
+    Runtime = 22,
+    Template = 23,
+}
+
+impl BaseDirectory {
+    /// Resolves the directory to its path.
+    pub async fn path(self) -> crate::Result<PathBuf> {
+        match self {
+            Self::Audio => audio_dir().await,
+            Self::Cache => cache_dir().await,
+            Self::Config => config_dir().await,
+            Self::Data => data_dir().await,
+            Self::LocalData => local_data_dir().await,
+            Self::Document => document_dir().await,
+            Self::Download => download_dir().await,
+            Self::Picture => picture_dir().await,
+            Self::Public => public_dir().await,
+            Self::Video => video_dir().await,
+            Self::Resource => resource_dir().await,
+            Self::Temp => temp_dir().await,
+            Self::AppConfig => app_config_dir().await,
+            Self::AppData => app_data_dir().await,
+            Self::AppLocalData => app_local_data_dir().await,
+            Self::AppCache => app_cache_dir().await,
+            Self::AppLog => app_log_dir().await,
+            Self::Desktop => desktop_dir().await,
+            Self::Executable => executable_dir().await,
+            Self::Font => font_dir().await,
+            Self::Home => home_dir().await,
+            Self::Runtime => runtime_dir().await,
+            Self::Template => template_dir().await,
+        }
+    }
+}
+
 /// Returns the path to the suggested directory for your app's config files.
 ///
 /// Resolves to `${configDir}/${bundleIdentifier}`, where `bundleIdentifier` is the value [`tauri.bundle.identifier`](https://beta.tauri.app/references/v2/config/#identifier) is configured in `tauri.conf.json`.
@@ -555,6 +623,53 @@ pub async fn app_log_dir() -> crate::Result<PathBuf> {
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
+/// Returns a temporary directory.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::temp_dir;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let temp_dir_path = temp_dir().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn temp_dir() -> crate::Result<PathBuf> {
+    let raw = base::tempDir().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Resolves `path` relative to the `base` directory into an absolute path.
+///
+/// An absolute `path` is returned normalized, ignoring `base`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::{resolve_in, BaseDirectory};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = resolve_in(BaseDirectory::AppData, "users/tauri/avatar.png").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn resolve_in(base: BaseDirectory, path: impl AsRef<Path>) -> crate::Result<PathBuf> {
+    let path = path.as_ref();
+    let Some(path) = path.to_str() else {
+        return Err(crate::Error::Utf8(path.to_path_buf()));
+    };
+
+    let dir = base.path().await?;
+    let Some(dir) = dir.to_str() else {
+        return Err(crate::Error::Utf8(dir));
+    };
+
+    resolve([dir, path]).await
+}
+
 /// Resolves a sequence of `paths` or `path` segments into an absolute path.
 ///
 /// # Example
@@ -708,6 +823,490 @@ pub async fn is_absolute(path: &str) -> crate::Result<bool> {
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
+/// Returns the platform-specific path segment separator, `\` on Windows and `/` on POSIX.
+///
+/// The value is queried from the backend once and cached.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::sep;
+///
+/// let path = ["users", "tauri", "avatar.png"].join(sep());
+/// ```
+pub fn sep() -> &'static str {
+    static SEP: OnceLock<String> = OnceLock::new();
+
+    SEP.get_or_init(|| base::sep().as_string().unwrap_or_else(|| "/".into()))
+}
+
+/// Returns the platform-specific path segment delimiter, `;` on Windows and `:` on POSIX.
+///
+/// The value is queried from the backend once and cached.
+pub fn delimiter() -> &'static str {
+    static DELIMITER: OnceLock<String> = OnceLock::new();
+
+    DELIMITER.get_or_init(|| base::delimiter().as_string().unwrap_or_else(|| ":".into()))
+}
+
+fn is_windows() -> bool {
+    sep() == "\\"
+}
+
+fn is_sep(c: char) -> bool {
+    c == '/' || (c == '\\' && is_windows())
+}
+
+/// Splits `path` into its root, such as `/`, `C:\` or `\\server\share\`, and the rest.
+fn split_root(path: &str) -> (&str, &str) {
+    if !is_windows() {
+        let rest = path.trim_start_matches('/');
+        return (&path[..path.len() - rest.len()], rest);
+    }
+
+    let bytes = path.as_bytes();
+
+    if bytes.len() >= 2 && is_sep(bytes[0] as char) && is_sep(bytes[1] as char) {
+        // UNC path, the root spans the server and share names
+        let mut end = 2;
+        for _ in 0..2 {
+            end += path[end..].find(is_sep).map(|i| i + 1).unwrap_or(path.len() - end);
+        }
+        return path.split_at(end);
+    }
+
+    let mut end = 0;
+    if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        end = 2;
+    }
+    let rest = path[end..].trim_start_matches(is_sep);
+
+    (&path[..path.len() - rest.len()], rest)
+}
+
+/// Collapses the separators of a root into [`sep`].
+fn normalize_root(root: &str) -> String {
+    if !is_windows() {
+        return if root.is_empty() { "" } else { "/" }.into();
+    }
+
+    let trimmed = root.trim_end_matches(is_sep);
+    let mut normalized = trimmed.replace('/', "\\");
+    if trimmed.len() < root.len() {
+        normalized.push('\\');
+    }
+
+    normalized
+}
+
+/// Synchronous version of [`is_absolute`], computed in Rust without a backend call.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::is_absolute_sync;
+///
+/// assert!(is_absolute_sync("/home/tauri"));
+/// ```
+pub fn is_absolute_sync(path: &str) -> bool {
+    let (root, _) = split_root(path);
+
+    if is_windows() {
+        // `\foo` and `C:foo` are relative to the current drive or directory
+        root.len() > 2 && (root.ends_with(is_sep) || root.starts_with(is_sep))
+    } else {
+        !root.is_empty()
+    }
+}
+
+/// Lexically normalizes `path`, resolving `'..'` and `'.'` segments without touching the file system.
+///
+/// This is not an exact synchronous [`normalize`]: symbolic links are not resolved,
+/// so the result may differ for paths going through them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::normalize_sync;
+///
+/// // on POSIX
+/// assert_eq!(normalize_sync("/users/./tauri/../avatar.png"), "/users/avatar.png");
+/// ```
+pub fn normalize_sync(path: &str) -> String {
+    let (root, rest) = split_root(path);
+    let mut segments: Vec<&str> = Vec::new();
+
+    for segment in rest.split(is_sep) {
+        match segment {
+            "" | "." => {}
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                // `..` above the root is the root itself
+                _ if !root.is_empty() => {}
+                _ => segments.push(".."),
+            },
+            segment => segments.push(segment),
+        }
+    }
+
+    let mut normalized = normalize_root(root);
+    normalized.push_str(&segments.join(sep()));
+
+    if normalized.is_empty() {
+        return ".".into();
+    }
+    if !segments.is_empty() && rest.ends_with(is_sep) {
+        normalized.push_str(sep());
+    }
+
+    normalized
+}
+
+/// Synchronous version of [`join`], joining `paths` with [`sep`] and normalizing the result.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::join_sync;
+///
+/// // on POSIX
+/// assert_eq!(join_sync(["/users", "tauri", "..", "avatar.png"]), "/users/avatar.png");
+/// ```
+pub fn join_sync<'a>(paths: impl IntoIterator<Item = &'a str>) -> String {
+    let paths: Vec<_> = paths.into_iter().filter(|path| !path.is_empty()).collect();
+
+    normalize_sync(&paths.join(sep()))
+}
+
+/// Trims trailing separators, keeping the root.
+fn trim_end_sep(path: &str) -> &str {
+    let (root, rest) = split_root(path);
+
+    &path[..root.len() + rest.trim_end_matches(is_sep).len()]
+}
+
+/// Synchronous version of [`dirname`]. Trailing directory separators are ignored.
+///
+/// Returns `None` if the `path` is a root or empty.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::dirname_sync;
+///
+/// // on POSIX
+/// assert_eq!(dirname_sync("/users/tauri/"), Some("/users"));
+/// ```
+pub fn dirname_sync(path: &str) -> Option<&str> {
+    let path = trim_end_sep(path);
+    let (root, rest) = split_root(path);
+
+    if rest.is_empty() {
+        return None;
+    }
+
+    match rest.rfind(is_sep) {
+        Some(i) => Some(trim_end_sep(&path[..root.len() + i])),
+        None => Some(root),
+    }
+}
+
+/// Synchronous version of [`basename`]. Trailing directory separators are ignored.
+///
+/// `ext` is removed from the end of the returned name.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::basename_sync;
+///
+/// assert_eq!(basename_sync("users/tauri/app.conf", Some(".conf")), Some("app"));
+/// ```
+pub fn basename_sync<'a>(path: &'a str, ext: Option<&str>) -> Option<&'a str> {
+    let path = trim_end_sep(path);
+    let (root, rest) = split_root(path);
+
+    let name = &path[root.len() + rest.rfind(is_sep).map(|i| i + 1).unwrap_or(0)..];
+    if name.is_empty() || name == ".." {
+        return None;
+    }
+
+    Some(match ext {
+        Some(ext) => name.strip_suffix(ext).filter(|name| !name.is_empty()).unwrap_or(name),
+        None => name,
+    })
+}
+
+/// Synchronous version of [`extname`], returning the extension without its leading `.`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::extname_sync;
+///
+/// assert_eq!(extname_sync("users/tauri/app.conf"), Some("conf"));
+/// assert_eq!(extname_sync("users/tauri/.bashrc"), None);
+/// ```
+pub fn extname_sync(path: &str) -> Option<&str> {
+    let name = basename_sync(path, None)?;
+
+    match name.rfind('.') {
+        Some(0) | None => None,
+        Some(i) => Some(&name[i + 1..]),
+    }
+}
+
+/// Every [`BaseDirectory`], in declaration order.
+const BASE_DIRECTORIES: [BaseDirectory; 23] = [
+    BaseDirectory::Audio,
+    BaseDirectory::Cache,
+    BaseDirectory::Config,
+    BaseDirectory::Data,
+    BaseDirectory::LocalData,
+    BaseDirectory::Document,
+    BaseDirectory::Download,
+    BaseDirectory::Picture,
+    BaseDirectory::Public,
+    BaseDirectory::Video,
+    BaseDirectory::Resource,
+    BaseDirectory::Temp,
+    BaseDirectory::AppConfig,
+    BaseDirectory::AppData,
+    BaseDirectory::AppLocalData,
+    BaseDirectory::AppCache,
+    BaseDirectory::AppLog,
+    BaseDirectory::Desktop,
+    BaseDirectory::Executable,
+    BaseDirectory::Font,
+    BaseDirectory::Home,
+    BaseDirectory::Runtime,
+    BaseDirectory::Template,
+];
+
+/// Well-known directories resolved once per session.
+///
+/// The accessors are synchronous. Loading awaits once, but is not a single round-trip:
+/// the backend has no batch command, so one request per [`BaseDirectory`] is sent concurrently.
+/// Directories that are not available on the current platform, such as [`runtime_dir`] on macOS, are `None`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::api::path::{BaseDirectory, PathResolver};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let paths = PathResolver::get().await?;
+///
+/// let config = paths.app_config_dir();
+/// let avatar = paths.resolve_in(BaseDirectory::AppData, "users/tauri/avatar.png")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PathResolver {
+    dirs: HashMap<BaseDirectory, PathBuf>,
+}
+
+impl PathResolver {
+    /// Gets the resolver, resolving all directories on the first successful call.
+    ///
+    /// Errors are not cached, the next call retries.
+    pub async fn get() -> crate::Result<&'static Self> {
+        static RESOLVER: OnceLock<PathResolver> = OnceLock::new();
+
+        if let Some(resolver) = RESOLVER.get() {
+            return Ok(resolver);
+        }
+
+        let resolver = Self::load().await?;
+
+        Ok(RESOLVER.get_or_init(|| resolver))
+    }
+
+    /// Resolves all directories without caching the resolver.
+    ///
+    /// Fails if any directory fails for another reason than being unavailable on the current platform,
+    /// such as a missing `core:path` permission.
+    pub async fn load() -> crate::Result<Self> {
+        let paths = future::join_all(BASE_DIRECTORIES.map(BaseDirectory::path)).await;
+
+        let mut dirs = HashMap::with_capacity(BASE_DIRECTORIES.len());
+        for (base, path) in BASE_DIRECTORIES.into_iter().zip(paths) {
+            match path {
+                Ok(path) => {
+                    dirs.insert(base, path);
+                }
+                // The backend's error for directories the platform doesn't have
+                Err(crate::Error::Command(e)) if e.contains("unknown path") => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Queries and caches the separators along the way
+        sep();
+        delimiter();
+
+        Ok(Self { dirs })
+    }
+
+    /// Gets the path of the `base` directory.
+    pub fn dir(&self, base: BaseDirectory) -> Option<&Path> {
+        self.dirs.get(&base).map(PathBuf::as_path)
+    }
+
+    /// Synchronous version of [`resolve_in`], resolving `path` relative to the `base` directory.
+    ///
+    /// An absolute `path` is returned normalized, ignoring `base`.
+    pub fn resolve_in(&self, base: BaseDirectory, path: impl AsRef<Path>) -> crate::Result<PathBuf> {
+        let path = path.as_ref();
+        let Some(path) = path.to_str() else {
+            return Err(crate::Error::Utf8(path.to_path_buf()));
+        };
+
+        if is_absolute_sync(path) {
+            return Ok(normalize_sync(path).into());
+        }
+
+        let Some(dir) = self.dir(base) else {
+            return Err(crate::Error::Command(format!(
+                "{base:?} directory is not available on this platform"
+            )));
+        };
+        let Some(dir) = dir.to_str() else {
+            return Err(crate::Error::Utf8(dir.to_path_buf()));
+        };
+
+        Ok(join_sync([dir, path]).into())
+    }
+
+    /// Cached [`sep`].
+    pub fn sep(&self) -> &'static str {
+        sep()
+    }
+
+    /// Cached [`delimiter`].
+    pub fn delimiter(&self) -> &'static str {
+        delimiter()
+    }
+
+    /// Cached [`audio_dir`], the path to the user's audio directory.
+    pub fn audio_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Audio)
+    }
+
+    /// Cached [`cache_dir`], the path to the user's cache directory.
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Cache)
+    }
+
+    /// Cached [`config_dir`], the path to the user's config directory.
+    pub fn config_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Config)
+    }
+
+    /// Cached [`data_dir`], the path to the user's data directory.
+    pub fn data_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Data)
+    }
+
+    /// Cached [`local_data_dir`], the path to the user's local data directory.
+    pub fn local_data_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::LocalData)
+    }
+
+    /// Cached [`document_dir`], the path to the user's document directory.
+    pub fn document_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Document)
+    }
+
+    /// Cached [`download_dir`], the path to the user's download directory.
+    pub fn download_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Download)
+    }
+
+    /// Cached [`picture_dir`], the path to the user's picture directory.
+    pub fn picture_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Picture)
+    }
+
+    /// Cached [`public_dir`], the path to the user's public directory.
+    pub fn public_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Public)
+    }
+
+    /// Cached [`video_dir`], the path to the user's video directory.
+    pub fn video_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Video)
+    }
+
+    /// Cached [`resource_dir`], the path to the application's resource directory.
+    pub fn resource_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Resource)
+    }
+
+    /// Cached [`temp_dir`], the path to a temporary directory.
+    pub fn temp_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Temp)
+    }
+
+    /// Cached [`app_config_dir`], the path to the suggested directory for your app's config files.
+    pub fn app_config_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::AppConfig)
+    }
+
+    /// Cached [`app_data_dir`], the path to the suggested directory for your app's data files.
+    pub fn app_data_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::AppData)
+    }
+
+    /// Cached [`app_local_data_dir`], the path to the suggested directory for your app's local data files.
+    pub fn app_local_data_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::AppLocalData)
+    }
+
+    /// Cached [`app_cache_dir`], the path to the suggested directory for your app's cache files.
+    pub fn app_cache_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::AppCache)
+    }
+
+    /// Cached [`app_log_dir`], the path to the suggested directory for your app's log files.
+    pub fn app_log_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::AppLog)
+    }
+
+    /// Cached [`desktop_dir`], the path to the user's desktop directory.
+    pub fn desktop_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Desktop)
+    }
+
+    /// Cached [`executable_dir`], the path to the user's executable directory.
+    pub fn executable_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Executable)
+    }
+
+    /// Cached [`font_dir`], the path to the user's font directory.
+    pub fn font_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Font)
+    }
+
+    /// Cached [`home_dir`], the path to the user's home directory.
+    pub fn home_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Home)
+    }
+
+    /// Cached [`runtime_dir`], the path to the user's runtime directory.
+    pub fn runtime_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Runtime)
+    }
+
+    /// Cached [`template_dir`], the path to the user's template directory.
+    pub fn template_dir(&self) -> Option<&Path> {
+        self.dir(BaseDirectory::Template)
+    }
+}
+
 mod base {
     use wasm_bindgen::prelude::*;
 
@@ -735,6 +1334,7 @@ mod base {
         pub async fn dataDir() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn desktopDir() -> Result<JsValue, JsValue>;
+        pub fn delimiter() -> JsValue;
         #[wasm_bindgen(catch)]
         pub async fn dirname(path: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
@@ -769,6 +1369,9 @@ mod base {
         pub async fn resourceDir() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn runtimeDir() -> Result<JsValue, JsValue>;
+        pub fn sep() -> JsValue;
+        #[wasm_bindgen(catch)]
+        pub async fn tempDir() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn templateDir() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
@@ -150,12 +150,21 @@ pub enum Error {
     Command(String),
     #[error("Failed to parse JSON: {0}")]
     Serde(String),
-    #[cfg(any(feature = "event", feature = "window"))]
+    #[cfg(any(feature = "event", feature = "upload", feature = "window"))]
     #[error("Oneshot cancelled: {0}")]
     OneshotCanceled(#[from] futures::channel::oneshot::Canceled),
-    #[cfg(feature = "fs")]
+    #[cfg(any(feature = "fs", feature = "path", feature = "stronghold", feature = "upload"))]
     #[error("Could not convert path to string")]
     Utf8(std::path::PathBuf),
+    #[cfg(feature = "fs")]
+    #[error("Path has no file name: {}", .0.display())]
+    NoFileName(std::path::PathBuf),
+    #[cfg(feature = "biometric")]
+    #[error("Biometric authentication failed: {0}")]
+    Biometric(#[from] crate::plugin::biometric::BiometricError),
+    #[cfg(feature = "nfc")]
+    #[error("NFC language code is longer than 63 bytes: {0}")]
+    NfcLanguage(String),
 }
 
 impl From<serde_wasm_bindgen::Error> for Error {
@@ -1,8 +1,196 @@
-//! 
+//!
 //! Version: **authenticator-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-authenticator](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/authenticator)
 //!
-//! 
+//! Challenges and binary data are base64 (URL-safe, unpadded) encoded strings, as produced by the plugin.
+//!
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+/// The result of registering a security key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Registration {
+    pub key_handle: String,
+    pub pubkey: String,
+    pub register_data: String,
+    pub client_data: String,
+}
+
+/// A verified registration, store it to later verify signatures.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrationVerification {
+    pub key_handle: String,
+    #[serde(alias = "publicKey")]
+    pub pubkey: String,
+    #[serde(default)]
+    pub device_name: Option<String>,
+}
+
+/// A challenge signed by a security key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Signature {
+    pub key_handle: String,
+    pub sign_data: String,
+    #[serde(default)]
+    pub client_data: String,
+}
+
+/// The plugin returns registrations and signatures as JSON strings.
+fn from_json<T: serde::de::DeserializeOwned>(raw: JsValue) -> crate::Result<T> {
+    let raw = match raw.as_string() {
+        Some(json) => js_sys::JSON::parse(&json)?,
+        None => raw,
+    };
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Registers and authenticates with FIDO U2F security keys.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::authenticator::Authenticator;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let auth = Authenticator::init().await?;
+///
+/// let challenge = "9HQZ2yHL0MmRjHNqNbIYp8z9b5ZQ1A6N9ZMkNTU9ZxM";
+/// let registration = auth.register(challenge, "tauri.app").await?;
+/// let verified = auth
+///     .verify_registration(challenge, "tauri.app", &registration)
+///     .await?;
+///
+/// let signature = auth.sign(challenge, "tauri.app", &verified.key_handle).await?;
+/// let counter = auth
+///     .verify_signature(challenge, "tauri.app", &signature, &verified.pubkey)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Authenticator(base::Authenticator);
+
+impl Authenticator {
+    /// Initializes the authenticator.
+    pub async fn init() -> crate::Result<Self> {
+        let inner = base::Authenticator::new();
+        inner.init().await?;
+
+        Ok(Self(inner))
+    }
+
+    /// Registers a security key for `application`, waiting up to 10 seconds for the user.
+    pub async fn register(&self, challenge: &str, application: &str) -> crate::Result<Registration> {
+        let raw = self.0.register(challenge, application).await?;
+
+        from_json(raw)
+    }
+
+    /// Verifies a registration returned by [`Authenticator::register`].
+    pub async fn verify_registration(
+        &self,
+        challenge: &str,
+        application: &str,
+        registration: &Registration,
+    ) -> crate::Result<RegistrationVerification> {
+        let raw = self
+            .0
+            .verifyRegistration(
+                challenge,
+                application,
+                &registration.register_data,
+                &registration.client_data,
+            )
+            .await?;
+
+        from_json(raw)
+    }
+
+    /// Signs `challenge` with the registered key `key_handle`, waiting up to 10 seconds for the user.
+    pub async fn sign(
+        &self,
+        challenge: &str,
+        application: &str,
+        key_handle: &str,
+    ) -> crate::Result<Signature> {
+        let raw = self.0.sign(challenge, application, key_handle).await?;
+
+        from_json(raw)
+    }
+
+    /// Verifies a signature against the registered `pubkey` and returns the key's signature counter.
+    ///
+    /// The counter increases with each signature, a counter that did not increase indicates a cloned key.
+    pub async fn verify_signature(
+        &self,
+        challenge: &str,
+        application: &str,
+        signature: &Signature,
+        pubkey: &str,
+    ) -> crate::Result<u32> {
+        let raw = self
+            .0
+            .verifySignature(
+                challenge,
+                application,
+                &signature.sign_data,
+                &signature.client_data,
+                &signature.key_handle,
+                pubkey,
+            )
+            .await?;
+
+        Ok(serde_wasm_bindgen::from_value(raw)?)
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
-mod base {}
\ No newline at end of file
+    #[wasm_bindgen(module = "/src/scripts/plugins/authenticator.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Authenticator;
+        #[wasm_bindgen(constructor)]
+        pub fn new() -> Authenticator;
+        #[wasm_bindgen(method, catch)]
+        pub async fn init(this: &Authenticator) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn register(
+            this: &Authenticator,
+            challenge: &str,
+            application: &str,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn verifyRegistration(
+            this: &Authenticator,
+            challenge: &str,
+            application: &str,
+            registerData: &str,
+            clientData: &str,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn sign(
+            this: &Authenticator,
+            challenge: &str,
+            application: &str,
+            keyHandle: &str,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn verifySignature(
+            this: &Authenticator,
+            challenge: &str,
+            application: &str,
+            signData: &str,
+            clientData: &str,
+            keyHandle: &str,
+            pubkey: &str,
+        ) -> Result<JsValue, JsValue>;
+    }
+}
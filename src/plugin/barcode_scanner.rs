@@ -6,7 +6,6 @@
 
 use serde::{Deserialize, Serialize};
 
-
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PermissionState {
     #[serde(rename = "granted")]
@@ -15,6 +14,8 @@ pub enum PermissionState {
     Denied,
     #[serde(rename = "prompt")]
     Prompt,
+    #[serde(rename = "prompt-with-rationale")]
+    PromptWithRationale,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -45,6 +46,12 @@ pub enum Format {
     DataMatrix,
     #[serde(rename = "PDF_417")]
     PDF417,
+    #[serde(rename = "GS1_DATA_BAR")]
+    GS1DataBar,
+    #[serde(rename = "GS1_DATA_BAR_LIMITED")]
+    GS1DataBarLimited,
+    #[serde(rename = "GS1_DATA_BAR_EXPANDED")]
+    GS1DataBarExpanded,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -55,59 +62,131 @@ pub enum CameraDirection {
     Front,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct ScanOptions {
-    camera_direction: Option<CameraDirection>,
-    formats: Option<Format>,
-    windowed: Option<bool>,
+    /// The camera to scan with, defaults to the back camera.
+    pub camera_direction: Option<CameraDirection>,
+    /// The formats to look for, defaults to all formats.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub formats: Vec<Format>,
+    /// Shows the camera behind the transparent webview instead of fullscreen.
+    pub windowed: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// The area of a scanned code in the camera image.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Bounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Scanned {
-    content: String,
-    format: Format,
-    //bounds: unimplemented!(),
+    pub content: String,
+    pub format: Format,
+    /// The area of the code, if the platform reports it.
+    #[serde(default, deserialize_with = "deserialize_bounds")]
+    pub bounds: Option<Bounds>,
 }
 
-/// Returns a bool checking if autostart is enabled.
-#[inline(always)]
-pub async fn cancel() -> crate::Result<()> {
-    let raw = base::cancel().await?;
+/// Platforms report bounds differently, unknown shapes are dropped instead of failing the scan.
+fn deserialize_bounds<'de, D>(deserializer: D) -> Result<Option<Bounds>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MaybeBounds {
+        Bounds(Bounds),
+        Other(serde::de::IgnoredAny),
+    }
+
+    Ok(match MaybeBounds::deserialize(deserializer)? {
+        MaybeBounds::Bounds(bounds) => Some(bounds),
+        MaybeBounds::Other(_) => None,
+    })
+}
+
+/// Cancels a pending scan when the scan future is dropped.
+struct CancelOnDrop {
+    armed: bool,
+}
 
-    Ok(raw)
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if self.armed {
+            wasm_bindgen_futures::spawn_local(async {
+                let _ = base::cancel().await;
+            });
+        }
+    }
 }
 
-/// Returns a bool checking if autostart is enabled.
+/// Scans a barcode with the camera.
+///
+/// Dropping the returned future before it resolves cancels the scan, closing the camera.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::barcode_scanner::{scan, Format, ScanOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let scanned = scan(&ScanOptions {
+///     formats: vec![Format::QRCode, Format::EAN13],
+///     windowed: true,
+///     ..Default::default()
+/// })
+/// .await?;
+///
+/// println!("{}", scanned.content);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn scan(options: &ScanOptions) -> crate::Result<Scanned> {
+    let options = serde_wasm_bindgen::to_value(options)?;
+
+    let mut guard = CancelOnDrop { armed: true };
+    let raw = base::scan(options).await;
+    guard.armed = false;
+
+    Ok(serde_wasm_bindgen::from_value(raw?)?)
+}
+
+/// Cancels the current scan.
 #[inline(always)]
-pub async fn check_permissions() -> crate::Result<()> {
-    let raw = base::check_permissions().await?;
+pub async fn cancel() -> crate::Result<()> {
+    base::cancel().await?;
 
-    Ok(raw)
+    Ok(())
 }
 
-/// Returns a bool checking if autostart is enabled.
+/// Checks the camera permission.
 #[inline(always)]
-pub async fn open_app_settings() -> crate::Result<bool> {
-    let raw = base::open_app_settings().await?;
+pub async fn check_permissions() -> crate::Result<PermissionState> {
+    let raw = base::check_permissions().await?;
 
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
-/// Returns a bool checking if autostart is enabled.
+/// Requests the camera permission.
 #[inline(always)]
-pub async fn request_permissions() -> crate::Result<bool> {
+pub async fn request_permissions() -> crate::Result<PermissionState> {
     let raw = base::request_permissions().await?;
 
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
-/// Returns a bool checking if autostart is enabled.
-//#[inline(always)]
-//pub async fn scan() -> crate::Result<bool> {
-//    let raw = base::scan().await?;
-//
-//    Ok(serde_wasm_bindgen::from_value(raw)?)
-//}
+/// Opens the app settings, to grant a denied permission.
+#[inline(always)]
+pub async fn open_app_settings() -> crate::Result<()> {
+    base::open_app_settings().await?;
+
+    Ok(())
+}
 
 mod base {
     use wasm_bindgen::prelude::*;
@@ -117,12 +196,12 @@ mod base {
         #[wasm_bindgen(catch, js_name = "cancel")]
         pub async fn cancel() -> Result<(), JsValue>;
         #[wasm_bindgen(catch, js_name = "checkPermissions")]
-        pub async fn check_permissions() -> Result<(), JsValue>;
+        pub async fn check_permissions() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch, js_name = "openAppSettings")]
-        pub async fn open_app_settings() -> Result<JsValue, JsValue>;
+        pub async fn open_app_settings() -> Result<(), JsValue>;
         #[wasm_bindgen(catch, js_name = "requestPermissions")]
         pub async fn request_permissions() -> Result<JsValue, JsValue>;
-        //#[wasm_bindgen(catch, js_name = "scan")]
-        //pub async fn scan(options : ScanOptions) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch, js_name = "scan")]
+        pub async fn scan(options: JsValue) -> Result<JsValue, JsValue>;
     }
 }
\ No newline at end of file
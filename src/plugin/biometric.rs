@@ -1,7 +1,162 @@
-//! 
+//!
 //! Version: **biometric-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-biometric](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/biometric)
 //!
 
-mod base {}
\ No newline at end of file
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use wasm_bindgen::JsValue;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BiometryType {
+    None = 0,
+    TouchID = 1,
+    FaceID = 2,
+    Iris = 3,
+}
+
+/// The reason a biometric authentication failed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[serde(rename_all = "camelCase")]
+pub enum BiometricError {
+    #[error("the app cancelled the authentication")]
+    AppCancel,
+    #[error("the user failed to provide valid credentials")]
+    AuthenticationFailed,
+    #[error("the authentication context is invalid")]
+    InvalidContext,
+    #[error("the authentication requires user interaction")]
+    NotInteractive,
+    #[error("no passcode is set on the device")]
+    PasscodeNotSet,
+    #[error("the system cancelled the authentication")]
+    SystemCancel,
+    #[error("the user cancelled the authentication")]
+    UserCancel,
+    #[error("the user chose the fallback authentication")]
+    UserFallback,
+    #[error("biometry is locked after too many failed attempts")]
+    BiometryLockout,
+    #[error("biometry is not available on the device")]
+    BiometryNotAvailable,
+    #[error("no biometric identity is enrolled")]
+    BiometryNotEnrolled,
+    #[serde(other)]
+    #[error("unknown biometric error")]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    pub is_available: bool,
+    pub biometry_type: BiometryType,
+    /// Why biometry is unavailable, if it is.
+    pub error: Option<String>,
+    pub error_code: Option<BiometricError>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthOptions {
+    /// Falls back to the device passcode or password when biometry is unavailable.
+    pub allow_device_credential: Option<bool>,
+    /// iOS only, label of the cancel button.
+    pub cancel_title: Option<String>,
+    /// iOS only, label of the fallback button.
+    pub fallback_title: Option<String>,
+    /// Android only, title of the dialog.
+    pub title: Option<String>,
+    /// Android only, subtitle of the dialog.
+    pub subtitle: Option<String>,
+    /// Android only, requires an explicit confirmation after passive biometry such as face unlock.
+    pub confirmation_required: Option<bool>,
+    /// Android only, the number of failed attempts before the authentication is cancelled.
+    #[serde(rename = "maxAttemps")]
+    pub max_attempts: Option<u32>,
+}
+
+/// Gets the biometric status of the device.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::biometric::check_status;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let status = check_status().await?;
+///
+/// if !status.is_available {
+///     // hide biometric login
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn check_status() -> crate::Result<Status> {
+    let raw = base::checkStatus().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Prompts the user for biometric authentication.
+///
+/// Known failures are returned as [`crate::Error::Biometric`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::biometric::{authenticate, AuthOptions, BiometricError};
+/// use tauri_wasm::Error;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// match authenticate("Unlock your vault", &AuthOptions::default()).await {
+///     Ok(()) => {}
+///     Err(Error::Biometric(BiometricError::UserCancel)) => {}
+///     Err(e) => return Err(e.into()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn authenticate(reason: &str, options: &AuthOptions) -> crate::Result<()> {
+    base::authenticate(reason, serde_wasm_bindgen::to_value(options)?)
+        .await
+        .map_err(into_error)
+}
+
+/// Mobile plugins reject with `{ message, code }`.
+fn into_error(raw: JsValue) -> crate::Error {
+    if let Some(message) = raw.as_string() {
+        return crate::Error::Command(message);
+    }
+
+    let code = js_sys::Reflect::get(&raw, &JsValue::from_str("code"))
+        .ok()
+        .and_then(|code| serde_wasm_bindgen::from_value::<BiometricError>(code).ok());
+
+    match code {
+        Some(code) if code != BiometricError::Unknown => crate::Error::Biometric(code),
+        _ => match js_sys::Reflect::get(&raw, &JsValue::from_str("message"))
+            .ok()
+            .and_then(|message| message.as_string())
+        {
+            Some(message) => crate::Error::Command(message),
+            None => raw.into(),
+        },
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/biometric.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn checkStatus() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn authenticate(reason: &str, options: JsValue) -> Result<(), JsValue>;
+    }
+}
@@ -1,11 +1,171 @@
-//! 
+//!
 //! Version: **cli-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-cli](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/cli)
 //!
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The value of a matched argument.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ArgValue {
+    /// The argument was not provided and has no default value.
+    Null,
+    /// A flag, which does not take a value.
+    Bool(bool),
+    /// An argument taking a single value.
+    String(String),
+    /// An argument taking multiple values.
+    Array(Vec<String>),
+}
+
+impl ArgValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[String]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+/// A matched argument.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArgMatch {
+    pub value: ArgValue,
+    /// How many times the argument was provided.
+    pub occurrences: u32,
+}
+
+/// A matched subcommand.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SubcommandMatch {
+    pub name: String,
+    pub matches: CliMatches,
+}
+
+/// The arguments and subcommand the app was started with.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CliMatches {
+    pub args: HashMap<String, ArgMatch>,
+    pub subcommand: Option<Box<SubcommandMatch>>,
+}
+
+impl CliMatches {
+    /// Gets the match of the argument `name`.
+    pub fn get(&self, name: &str) -> Option<&ArgMatch> {
+        self.args.get(name)
+    }
+
+    /// Whether the flag `name` was provided.
+    pub fn get_flag(&self, name: &str) -> bool {
+        self.get(name)
+            .and_then(|arg| arg.value.as_bool())
+            .unwrap_or_default()
+    }
+
+    /// Gets the value of the argument `name`.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(|arg| arg.value.as_str())
+    }
+
+    /// Gets the values of the argument `name`, a single value is returned as one element.
+    pub fn get_many(&self, name: &str) -> Vec<&str> {
+        match self.get(name).map(|arg| &arg.value) {
+            Some(ArgValue::String(value)) => vec![value.as_str()],
+            Some(ArgValue::Array(values)) => values.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// How many times the argument `name` was provided.
+    pub fn occurrences(&self, name: &str) -> u32 {
+        self.get(name).map(|arg| arg.occurrences).unwrap_or_default()
+    }
+
+    /// Maps the matches into `T`.
+    pub fn parse<T: FromMatches>(&self) -> crate::Result<T> {
+        T::from_matches(self)
+    }
+}
+
+/// Types that can be built from [`CliMatches`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::cli::{get_matches, CliMatches, FromMatches};
+///
+/// struct Config {
+///     verbose: bool,
+///     profile: Option<String>,
+///     files: Vec<String>,
+/// }
+///
+/// impl FromMatches for Config {
+///     fn from_matches(matches: &CliMatches) -> tauri_wasm::Result<Self> {
+///         Ok(Self {
+///             verbose: matches.get_flag("verbose"),
+///             profile: matches.get_str("profile").map(Into::into),
+///             files: matches.get_many("files").into_iter().map(Into::into).collect(),
+///         })
+///     }
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config: Config = get_matches().await?.parse()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait FromMatches: Sized {
+    fn from_matches(matches: &CliMatches) -> crate::Result<Self>;
+}
+
+impl FromMatches for CliMatches {
+    fn from_matches(matches: &CliMatches) -> crate::Result<Self> {
+        Ok(matches.clone())
+    }
+}
+
+/// Gets the arguments the app was started with, parsed according to the `plugins > cli` config.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::cli::get_matches;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let matches = get_matches().await?;
+///
+/// if let Some(subcommand) = &matches.subcommand {
+///     if subcommand.name == "update" {
+///         let force = subcommand.matches.get_flag("force");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[inline(always)]
-pub async fn get_matches() -> crate::Result<bool> {
+pub async fn get_matches() -> crate::Result<CliMatches> {
     let raw = base::get_matches().await?;
 
     Ok(serde_wasm_bindgen::from_value(raw)?)
@@ -19,4 +179,4 @@ mod base {
         #[wasm_bindgen(catch, js_name = "getMatches")]
         pub async fn get_matches() -> Result<JsValue, JsValue>;
     }
-}
\ No newline at end of file
+}
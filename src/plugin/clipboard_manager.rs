@@ -4,6 +4,9 @@
 //! link to plugin: [tauri-plugin-clipboard-manager](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/clipboard-manager)
 //!
 
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+
 /// Gets the clipboard content as plain text.
 ///
 /// # Example
@@ -37,6 +40,91 @@ pub async fn write_text(text: &str) -> crate::Result<()> {
     Ok(inner::writeText(text).await?)
 }
 
+/// An image in RGBA format, in row-major order from top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Image {
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Deserialize)]
+struct ImageSize {
+    width: u32,
+    height: u32,
+}
+
+/// Gets the clipboard content as an image.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::clipboard_manager;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let image = clipboard_manager::read_image().await?;
+/// assert_eq!(image.rgba.len(), (image.width * image.height * 4) as usize);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn read_image() -> crate::Result<Image> {
+    let image: inner::Image = inner::readImage().await?.unchecked_into();
+
+    let rgba = image.rgba().await.map(|raw| js_sys::Uint8Array::new(&raw).to_vec());
+    let size = image.size().await;
+    // the image is a resource on the backend, free it before surfacing errors
+    let _ = image.close().await;
+
+    let size: ImageSize = serde_wasm_bindgen::from_value(size?)?;
+
+    Ok(Image {
+        rgba: rgba?,
+        width: size.width,
+        height: size.height,
+    })
+}
+
+/// Writes an image to the clipboard.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::clipboard_manager::{self, Image};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let red_pixel = Image { rgba: vec![255, 0, 0, 255], width: 1, height: 1 };
+/// clipboard_manager::write_image(&red_pixel).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn write_image(image: &Image) -> crate::Result<()> {
+    Ok(inner::writeImage(serde_wasm_bindgen::to_value(image)?).await?)
+}
+
+/// Writes HTML to the clipboard, with `alt_text` as the plain text fallback.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::clipboard_manager;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// clipboard_manager::write_html("<b>Tauri</b> is awesome!", Some("Tauri is awesome!")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn write_html(html: &str, alt_text: Option<&str>) -> crate::Result<()> {
+    Ok(inner::writeHtml(html, alt_text).await?)
+}
+
+/// Clears the clipboard.
+#[inline(always)]
+pub async fn clear() -> crate::Result<()> {
+    Ok(inner::clear().await?)
+}
+
 mod inner {
     use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -46,5 +134,25 @@ mod inner {
         pub async fn readText() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn writeText(text: &str) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn readImage() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn writeImage(image: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn writeHtml(html: &str, altText: Option<&str>) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn clear() -> Result<(), JsValue>;
     }
-}
\ No newline at end of file
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// The image resource returned by `readImage`.
+        pub type Image;
+        #[wasm_bindgen(method, catch)]
+        pub async fn rgba(this: &Image) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn size(this: &Image) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn close(this: &Image) -> Result<(), JsValue>;
+    }
+}
@@ -1,7 +1,277 @@
-//! 
+//!
 //! Version: **deep-link-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-deep-link](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/deep-link)
 //!
 
-mod base {}
\ No newline at end of file
+use crate::api::event::Listen;
+use futures::{channel::mpsc, Stream};
+use percent_encoding::percent_decode_str;
+use std::collections::HashMap;
+use url::Url;
+use wasm_bindgen::{prelude::Closure, JsValue};
+
+/// Gets the URLs the app was started with, if any.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::deep_link::get_current;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let urls = get_current().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn get_current() -> crate::Result<Option<Vec<Url>>> {
+    let raw = base::getCurrent().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Registers the app as the default handler for `protocol`.
+///
+/// #### Platform-specific
+///
+/// - **macOS / Android / iOS:** Unsupported, schemes must be set in the config.
+#[inline(always)]
+pub async fn register(protocol: &str) -> crate::Result<()> {
+    base::register(protocol).await?;
+
+    Ok(())
+}
+
+/// Unregisters the app as the default handler for `protocol`.
+///
+/// #### Platform-specific
+///
+/// - **macOS / Linux / Android / iOS:** Unsupported.
+#[inline(always)]
+pub async fn unregister(protocol: &str) -> crate::Result<()> {
+    base::unregister(protocol).await?;
+
+    Ok(())
+}
+
+/// Checks whether the app is the default handler for `protocol`.
+///
+/// #### Platform-specific
+///
+/// - **macOS / Android / iOS:** Unsupported.
+#[inline(always)]
+pub async fn is_registered(protocol: &str) -> crate::Result<bool> {
+    let raw = base::isRegistered(protocol).await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Listen to URLs opened while the app is running.
+///
+/// URLs that fail to parse are skipped.
+/// The returned Stream will automatically clean up it's underlying event listener when dropped, so no manual unlisten function needs to be called.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::deep_link::on_open_url;
+/// use futures::StreamExt;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut urls = on_open_url().await?;
+///
+/// while let Some(url) = urls.next().await {
+///     // navigate to url.path()
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn on_open_url() -> crate::Result<impl Stream<Item = Url>> {
+    let (tx, rx) = mpsc::unbounded::<Url>();
+
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+        let urls: Vec<String> = serde_wasm_bindgen::from_value(raw).unwrap_or_default();
+
+        for url in urls.iter().filter_map(|url| Url::parse(url).ok()) {
+            let _ = tx.unbounded_send(url);
+        }
+    });
+    let unlisten = base::onOpenUrl(&closure).await?;
+    closure.forget();
+
+    Ok(Listen {
+        rx,
+        unlisten: js_sys::Function::from(unlisten),
+    })
+}
+
+/// The parameters captured by a [`Router`] pattern.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params {
+    params: HashMap<String, String>,
+    query: HashMap<String, String>,
+}
+
+impl Params {
+    /// Gets the percent-decoded segment captured by `:name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+
+    /// Gets the value of the query parameter `name`.
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Param(String),
+    Rest,
+}
+
+type Handler<T> = Box<dyn Fn(&Url, &Params) -> Option<T>>;
+
+/// Maps deep link URLs to typed routes.
+///
+/// Patterns have the form `scheme://host/path`, where a segment starting with `:` captures the segment
+/// and a trailing `*` matches any remaining segments. The host counts as the first segment.
+/// Routes are tried in the order they were added.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::deep_link::{on_open_url, Router};
+/// use futures::StreamExt;
+///
+/// enum Route {
+///     Settings(String),
+///     Invite { code: String },
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut router = Router::new();
+/// router
+///     .route("myapp://settings/:section", |_, params| Some(Route::Settings(params.get("section")?.into())))
+///     .route("myapp://invite", |_, params| Some(Route::Invite { code: params.query("code")?.into() }));
+///
+/// let mut routes = on_open_url().await?.filter_map(|url| std::future::ready(router.resolve(&url)));
+///
+/// while let Some(route) = routes.next().await {
+///     // navigate to route
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Router<T> {
+    routes: Vec<(String, Vec<Segment>, Handler<T>)>,
+}
+
+impl<T> Default for Router<T> {
+    fn default() -> Self {
+        Self { routes: Vec::new() }
+    }
+}
+
+impl<T> Router<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route, `handler` may return `None` to fall through to the next route.
+    pub fn route(
+        &mut self,
+        pattern: &str,
+        handler: impl Fn(&Url, &Params) -> Option<T> + 'static,
+    ) -> &mut Self {
+        let (scheme, rest) = pattern.split_once("://").unwrap_or((pattern, ""));
+
+        let segments = rest
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment {
+                "*" => Segment::Rest,
+                _ => match segment.strip_prefix(':') {
+                    Some(name) => Segment::Param(name.to_string()),
+                    None => Segment::Literal(segment.to_string()),
+                },
+            })
+            .collect();
+
+        self.routes
+            .push((scheme.to_ascii_lowercase(), segments, Box::new(handler)));
+        self
+    }
+
+    /// Resolves `url` with the first matching route.
+    pub fn resolve(&self, url: &Url) -> Option<T> {
+        let segments: Vec<&str> = url
+            .host_str()
+            .into_iter()
+            .chain(url.path_segments().into_iter().flatten())
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+        self.routes
+            .iter()
+            .filter(|(scheme, _, _)| scheme == url.scheme())
+            .find_map(|(_, pattern, handler)| {
+                let params = Self::matches(pattern, &segments)?;
+
+                handler(
+                    url,
+                    &Params {
+                        params,
+                        query: query.clone(),
+                    },
+                )
+            })
+    }
+
+    fn matches(pattern: &[Segment], segments: &[&str]) -> Option<HashMap<String, String>> {
+        let mut params = HashMap::new();
+
+        for (i, segment) in pattern.iter().enumerate() {
+            match segment {
+                Segment::Rest => return Some(params),
+                Segment::Param(name) => {
+                    let value = percent_decode_str(segments.get(i)?).decode_utf8_lossy();
+                    params.insert(name.clone(), value.into_owned());
+                }
+                Segment::Literal(literal) => {
+                    if *segments.get(i)? != literal.as_str() {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        (pattern.len() == segments.len()).then_some(params)
+    }
+}
+
+mod base {
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/deep-link.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn getCurrent() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn register(protocol: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn unregister(protocol: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn isRegistered(protocol: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn onOpenUrl(
+            handler: &Closure<dyn FnMut(JsValue)>,
+        ) -> Result<JsValue, JsValue>;
+    }
+}
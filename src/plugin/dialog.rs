@@ -8,7 +8,7 @@ use js_sys::Array;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
-use crate::utils::ArrayIterator;
+use crate::{api::path::BaseDirectory, utils::ArrayIterator};
 #[derive(Debug, Clone, Copy, Hash, Serialize)]
 struct DialogFilter<'a> {
     extensions: &'a [&'a str],
@@ -38,6 +38,8 @@ pub struct FileResponse {
 #[serde(rename_all = "camelCase")]
 pub struct FileDialogBuilder<'a> {
     default_path: Option<&'a Path>,
+    #[serde(skip)]
+    base_dir: Option<BaseDirectory>,
     filters: Vec<DialogFilter<'a>>,
     title: Option<&'a str>,
     directory: bool,
@@ -57,6 +59,28 @@ impl<'a> FileDialogBuilder<'a> {
         self
     }
 
+    /// Resolves the default path relative to `base_dir`, or starts in `base_dir` if no default path is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::{api::path::BaseDirectory, plugin::dialog::FileDialogBuilder};
+    /// use std::path::Path;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = FileDialogBuilder::new()
+    ///     .set_base_dir(BaseDirectory::Document)
+    ///     .set_default_path(Path::new("report.pdf"))
+    ///     .save()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_base_dir(&mut self, base_dir: BaseDirectory) -> &mut Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
     /// If directory is true, indicates that it will be read recursively later.
     /// Defines whether subdirectories will be allowed on the scope or not.
     ///
@@ -148,7 +172,7 @@ impl<'a> FileDialogBuilder<'a> {
     /// ```
     ///
     pub async fn pick_file(&self) -> crate::Result<Option<FileResponse>> {
-        let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
+        let raw = inner::open(self.to_options().await?).await?;
         // Deserialize into FileData
         let file_data: FileResponse = serde_wasm_bindgen::from_value(raw)?;
         // Return the file data wrapped in Some
@@ -171,7 +195,7 @@ impl<'a> FileDialogBuilder<'a> {
     pub async fn pick_files(&mut self) -> crate::Result<Option<impl Iterator<Item = FileResponse>>> {
         self.multiple = true;
     
-        let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
+        let raw = inner::open(self.to_options().await?).await?;
 
         match Array::try_from(raw) {
             Ok(files) => {
@@ -200,7 +224,7 @@ impl<'a> FileDialogBuilder<'a> {
     pub async fn pick_folder(&mut self) -> crate::Result<Option<PathBuf>> {
         self.directory = true;
 
-        let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
+        let raw = inner::open(self.to_options().await?).await?;
 
         Ok(serde_wasm_bindgen::from_value(raw)?)
     }
@@ -222,7 +246,7 @@ impl<'a> FileDialogBuilder<'a> {
         self.directory = true;
         self.multiple = true;
 
-        let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
+        let raw = inner::open(self.to_options().await?).await?;
 
         match Array::try_from(raw) {
             Ok(files) => {
@@ -255,10 +279,24 @@ impl<'a> FileDialogBuilder<'a> {
     /// ```
     ///
     pub async fn save(&self) -> crate::Result<Option<PathBuf>> {
-        let raw = inner::save(serde_wasm_bindgen::to_value(&self)?).await?;
+        let raw = inner::save(self.to_options().await?).await?;
 
         Ok(serde_wasm_bindgen::from_value(raw)?)
     }
+
+    async fn to_options(&self) -> crate::Result<wasm_bindgen::JsValue> {
+        let Some(base_dir) = self.base_dir else {
+            return Ok(serde_wasm_bindgen::to_value(&self)?);
+        };
+
+        let default_path =
+            crate::api::path::resolve_in(base_dir, self.default_path.unwrap_or(Path::new(""))).await?;
+
+        Ok(serde_wasm_bindgen::to_value(&FileDialogBuilder {
+            default_path: Some(&default_path),
+            ..self.clone()
+        })?)
+    }
 }
 
 /// Types of message, ask and confirm dialogs.
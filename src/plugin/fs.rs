@@ -1,497 +1,1472 @@
-//! 
+//!
 //! Version: **fs-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-fs](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/fs)
 //!
 
-use crate::Error;
-use js_sys::ArrayBuffer;
+use crate::{api::event::Listen, Error};
+use futures::{
+    channel::mpsc, ready, stream, AsyncRead, AsyncSeek, AsyncWrite, FutureExt, Stream,
+};
+use js_sys::Uint8Array;
 use serde::{Deserialize, Serialize};
-use serde_repr::*;
-use std::path::{Path, PathBuf};
-use std::str;
-
-#[derive(Serialize_repr, Clone, PartialEq, Eq, Debug)]
-#[repr(u16)]
-pub enum BaseDirectory {
-    Audio = 1,
-    Cache = 2,
-    Config = 3,
-    Data = 4,
-    LocalData = 5,
-    Desktop = 6,
-    Document = 7,
-    Download = 8,
-    Executable = 9,
-    Font = 10,
-    Home = 11,
-    Picture = 12,
-    Public = 13,
-    Runtime = 14,
-    Template = 15,
-    Video = 16,
-    Resource = 17,
-    App = 18,
-    Log = 19,
-    Temp = 20,
-    AppConfig = 21,
-    AppData = 22,
-    AppLocalData = 23,
-    AppCache = 24,
-    AppLog = 25,
-}
-
-#[derive(Deserialize, Clone, PartialEq, Debug)]
-pub struct FileEntry {
-    pub path: PathBuf,
-    pub name: Option<String>,
-    pub children: Option<Vec<FileEntry>>,
+use std::{
+    io::{self, SeekFrom},
+    mem,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+pub use crate::api::path::BaseDirectory;
+
+/// An entry of a directory.
+#[derive(Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DirEntry {
+    /// The name of the entry, including its extension.
+    pub name: String,
+    pub is_file: bool,
+    pub is_directory: bool,
+    pub is_symlink: bool,
 }
 
-#[derive(Serialize, Clone, PartialEq, Debug)]
-struct FsDirOptions {
-    pub dir: Option<BaseDirectory>,
-    pub recursive: Option<bool>,
+/// Options shared by most file system operations.
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FsOptions {
+    /// The directory `path` is relative to, `path` must be absolute if `None`.
+    pub base_dir: Option<BaseDirectory>,
 }
 
-#[derive(Serialize, Clone, PartialEq, Debug)]
-struct FsOptions {
-    pub dir: Option<BaseDirectory>,
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MkdirOptions {
+    pub base_dir: Option<BaseDirectory>,
+    /// Also creates missing parent directories.
+    pub recursive: bool,
+    /// Permissions of the directory on Unix, defaults to `0o777` before the umask.
+    pub mode: Option<u32>,
+}
+
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveOptions {
+    pub base_dir: Option<BaseDirectory>,
+    /// Also removes the contents of a non-empty directory.
+    pub recursive: bool,
+}
+
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyFileOptions {
+    pub from_path_base_dir: Option<BaseDirectory>,
+    pub to_path_base_dir: Option<BaseDirectory>,
+}
+
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameOptions {
+    pub old_path_base_dir: Option<BaseDirectory>,
+    pub new_path_base_dir: Option<BaseDirectory>,
+}
+
+/// Metadata of a file or directory.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FileInfo {
+    pub is_file: bool,
+    pub is_directory: bool,
+    pub is_symlink: bool,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The last modification time, if available on the platform.
+    pub mtime: Option<SystemTime>,
+    /// The last access time, if available on the platform.
+    pub atime: Option<SystemTime>,
+    /// The creation time, if available on the platform.
+    pub birthtime: Option<SystemTime>,
+    pub readonly: bool,
+    /// Windows only, the raw file attributes.
+    pub file_attributes: Option<u32>,
+    /// Unix only, the ID of the device containing the file.
+    pub dev: Option<u64>,
+    /// Unix only, the inode number.
+    pub ino: Option<u64>,
+    /// Unix only, the file type and permissions.
+    pub mode: Option<u32>,
+    /// Unix only, the number of hard links.
+    pub nlink: Option<u64>,
+    /// Unix only, the user ID of the owner.
+    pub uid: Option<u32>,
+    /// Unix only, the group ID of the owner.
+    pub gid: Option<u32>,
+    /// Unix only, the device ID of a special file.
+    pub rdev: Option<u64>,
+    /// Unix only, the block size for filesystem I/O.
+    pub blksize: Option<u64>,
+    /// Unix only, the number of allocated blocks.
+    pub blocks: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFileInfo {
+    is_file: bool,
+    is_directory: bool,
+    is_symlink: bool,
+    size: u64,
+    mtime: Option<f64>,
+    atime: Option<f64>,
+    birthtime: Option<f64>,
+    readonly: bool,
+    file_attributes: Option<u32>,
+    dev: Option<u64>,
+    ino: Option<u64>,
+    mode: Option<u32>,
+    nlink: Option<u64>,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    rdev: Option<u64>,
+    blksize: Option<u64>,
+    blocks: Option<u64>,
+}
+
+/// Converts milliseconds since the Unix epoch, `None` if the time is not representable.
+fn system_time(millis: f64) -> Option<SystemTime> {
+    let offset = Duration::try_from_secs_f64(millis.abs() / 1000.0).ok()?;
+
+    if millis < 0.0 {
+        UNIX_EPOCH.checked_sub(offset)
+    } else {
+        UNIX_EPOCH.checked_add(offset)
+    }
+}
+
+/// The guest-js turns timestamps into `Date`s, which can't be deserialized directly.
+fn parse_file_info(raw: JsValue) -> crate::Result<FileInfo> {
+    for key in ["mtime", "atime", "birthtime"] {
+        let key = JsValue::from_str(key);
+
+        if let Some(date) = js_sys::Reflect::get(&raw, &key)?.dyn_ref::<js_sys::Date>() {
+            js_sys::Reflect::set(&raw, &key, &JsValue::from_f64(date.get_time()))?;
+        }
+    }
+
+    let raw: RawFileInfo = serde_wasm_bindgen::from_value(raw)?;
+
+    Ok(FileInfo {
+        is_file: raw.is_file,
+        is_directory: raw.is_directory,
+        is_symlink: raw.is_symlink,
+        size: raw.size,
+        mtime: raw.mtime.and_then(system_time),
+        atime: raw.atime.and_then(system_time),
+        birthtime: raw.birthtime.and_then(system_time),
+        readonly: raw.readonly,
+        file_attributes: raw.file_attributes,
+        dev: raw.dev,
+        ino: raw.ino,
+        mode: raw.mode,
+        nlink: raw.nlink,
+        uid: raw.uid,
+        gid: raw.gid,
+        rdev: raw.rdev,
+        blksize: raw.blksize,
+        blocks: raw.blocks,
+    })
 }
 
-/*
 #[derive(Serialize, Clone, PartialEq, Debug)]
-struct FsTextFileOption {
-    pub contents: String,
-    path: PathBuf,
+#[serde(rename_all = "camelCase")]
+pub struct WriteFileOptions {
+    pub base_dir: Option<BaseDirectory>,
+    /// Appends to the end of the file instead of replacing its contents.
+    pub append: bool,
+    /// Creates the file if it does not exist, defaults to `true`.
+    pub create: bool,
+    /// Fails if the file already exists.
+    pub create_new: bool,
+    /// Permissions of a newly created file on Unix.
+    pub mode: Option<u32>,
+}
+
+impl Default for WriteFileOptions {
+    fn default() -> Self {
+        Self {
+            base_dir: None,
+            append: false,
+            create: true,
+            create_new: false,
+            mode: None,
+        }
+    }
 }
-*/
 
 /// Copies a file to a destination.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, CopyFileOptions};
+/// use std::path::Path;
 ///
-/// fs::copy_file(source, destination, BaseDirectory::Download).expect("could not copy file");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// fs::copy_file(
+///     Path::new("app.conf"),
+///     Path::new("app.conf.bk"),
+///     &CopyFileOptions {
+///         from_path_base_dir: Some(BaseDirectory::AppConfig),
+///         to_path_base_dir: Some(BaseDirectory::AppConfig),
+///     },
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// Requires [`plugins > fs > copyFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn copy_file(source: &Path, destination: &Path, dir: BaseDirectory) -> crate::Result<()> {
-    let Some(source) = source.to_str() else {
-        return Err(Error::Utf8(source.to_path_buf()));
+pub async fn copy_file(
+    from_path: &Path,
+    to_path: &Path,
+    options: &CopyFileOptions,
+) -> crate::Result<()> {
+    let Some(from_path) = from_path.to_str() else {
+        return Err(Error::Utf8(from_path.to_path_buf()));
     };
 
-    let Some(destination) = destination.to_str() else {
-        return Err(Error::Utf8(destination.to_path_buf()));
+    let Some(to_path) = to_path.to_str() else {
+        return Err(Error::Utf8(to_path.to_path_buf()));
     };
 
-    let raw = inner::copyFile(
-        source,
-        destination,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
-    )
-    .await?;
-
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    Ok(inner::copyFile(from_path, to_path, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
 /// Creates a directory.
-/// If one of the path's parent components doesn't exist the promise will be rejected.
+///
+/// Unless `options.recursive` is set, the call fails if one of the path's parent components doesn't exist.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, MkdirOptions};
+/// use std::path::Path;
 ///
-/// fs::create_dir(dir, BaseDirectory::Download).expect("could not create directory");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// fs::mkdir(
+///     Path::new("users/settings"),
+///     &MkdirOptions {
+///         base_dir: Some(BaseDirectory::AppLocalData),
+///         recursive: true,
+///         ..Default::default()
+///     },
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > createDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn create_dir(dir: &Path, base_dir: BaseDirectory) -> crate::Result<()> {
-    let recursive = Some(false);
-
-    let Some(dir) = dir.to_str() else {
-        return Err(Error::Utf8(dir.to_path_buf()));
+/// Requires [`plugins > fs > mkdir`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn mkdir(path: &Path, options: &MkdirOptions) -> crate::Result<()> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::createDir(
-        dir,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(base_dir),
-            recursive,
-        })?,
-    )
-    .await?)
+    Ok(inner::mkdir(path, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
-/// Creates a directory recursively.
+/// Checks if a path exists.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
 ///
-/// fs::create_dir_all(dir, BaseDirectory::Download).expect("could not create directory");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppData) };
+/// let file_exists = fs::exists(Path::new("avatar.png"), &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > createDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn create_dir_all(dir: &Path, base_dir: BaseDirectory) -> crate::Result<()> {
-    let recursive = Some(true);
-
-    let Some(dir) = dir.to_str() else {
-        return Err(Error::Utf8(dir.to_path_buf()));
+/// Requires [`plugins > fs > exists`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn exists(path: &Path, options: &FsOptions) -> crate::Result<bool> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::createDir(
-        dir,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(base_dir),
-            recursive,
-        })?,
-    )
-    .await?)
+    let raw = inner::exists(path, serde_wasm_bindgen::to_value(options)?).await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
-/// Checks if a path exists.
+/// Reads a file as a byte array.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
 ///
-/// let file_exists = fs::exists(path, BaseDirectory::Download).expect("could not check if path exists");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppData) };
+/// let contents = fs::read_file(Path::new("avatar.png"), &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > exists`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn exists(path: &Path, dir: BaseDirectory) -> crate::Result<bool> {
+/// Requires [`plugins > fs > readFile`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn read_file(path: &Path, options: &FsOptions) -> crate::Result<Vec<u8>> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    let raw = inner::exists(
-        path,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
-    )
-    .await?;
+    let raw = inner::readFile(path, serde_wasm_bindgen::to_value(options)?).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    Ok(Uint8Array::new(&raw).to_vec())
 }
 
-/// Reads a file as a byte array.
+/// Lists the entries of a directory.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppData) };
 ///
-/// let contents = fs::read_binary_file(filePath, BaseDirectory::Download).expect("could not read file contents");
+/// for entry in fs::read_dir(Path::new("users"), &options).await? {
+///     if entry.is_directory {
+///         // descend into entry.name
+///     }
+/// }
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > readBinaryFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn read_binary_file(path: &Path, dir: BaseDirectory) -> crate::Result<Vec<u8>> {
+/// Requires [`plugins > fs > readDir`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn read_dir(path: &Path, options: &FsOptions) -> crate::Result<Vec<DirEntry>> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    let raw = inner::readBinaryFile(
-        path,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
-    )
-    .await?;
+    let raw = inner::readDir(path, serde_wasm_bindgen::to_value(options)?).await?;
 
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
-/// List directory files.
+/// Read a file as an UTF-8 encoded string.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
 ///
-/// let files = fs::read_dir(path, BaseDirectory::Download).expect("could not read directory");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppConfig) };
+/// let contents = fs::read_text_file(Path::new("app.conf"), &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > readDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn read_dir(path: &Path, dir: BaseDirectory) -> crate::Result<Vec<FileEntry>> {
-    let recursive = Some(false);
+/// Requires [`plugins > fs > readTextFile`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn read_text_file(path: &Path, options: &FsOptions) -> crate::Result<String> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    let raw = inner::readDir(
-        path,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(dir),
-            recursive,
-        })?,
-    )
-    .await?;
+    let raw = inner::readTextFile(path, serde_wasm_bindgen::to_value(options)?).await?;
 
     Ok(serde_wasm_bindgen::from_value(raw)?)
 }
 
-/// List directory files recursively.
+/// Reads a file as UTF-8 text, line by line.
+///
+/// Lines are only read from the backend as the Stream is polled, so large files are never held in memory.
+/// The Stream ends after the first error, dropping it early closes the file.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use futures::StreamExt;
+/// use std::path::Path;
 ///
-/// let files = fs::read_dir_all(path, BaseDirectory::Download).expect("could not read directory");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppLog) };
+/// let mut lines = fs::read_text_file_lines(Path::new("app.log"), &options).await?;
+///
+/// while let Some(line) = lines.next().await {
+///     let line = line?;
+/// }
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > readDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn read_dir_all(path: &Path, dir: BaseDirectory) -> crate::Result<Vec<FileEntry>> {
-    let recursive = Some(true);
+/// Requires [`plugins > fs > readTextFileLines`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn read_text_file_lines(
+    path: &Path,
+    options: &FsOptions,
+) -> crate::Result<impl Stream<Item = crate::Result<String>>> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    let raw = inner::readDir(
-        path,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(dir),
-            recursive,
-        })?,
-    )
-    .await?;
+    let options = serde_wasm_bindgen::to_value(options)?;
+    let lines = Lines(
+        inner::readTextFileLines(path, options.clone())
+            .await?
+            .unchecked_into(),
+    );
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    // The iterator opens the file lazily, open it now so that dropping the stream early can close it
+    let args = js_sys::Object::new();
+    js_sys::Reflect::set(&args, &JsValue::from_str("path"), &JsValue::from_str(path))?;
+    js_sys::Reflect::set(&args, &JsValue::from_str("options"), &options)?;
+    let rid = inner::invoke("plugin:fs|read_text_file_lines", args.into()).await?;
+    lines.0.set_rid(&rid);
+
+    Ok(stream::unfold(Some(lines), |lines| async move {
+        let lines = lines?;
+
+        let next = match JsFuture::from(lines.0.next()).await {
+            Ok(next) => next,
+            Err(e) => return Some((Err(e.into()), None)),
+        };
+
+        let done = js_sys::Reflect::get(&next, &JsValue::from_str("done"))
+            .map(|done| done.is_truthy())
+            .unwrap_or(true);
+
+        if done {
+            return None;
+        }
+
+        let line = js_sys::Reflect::get(&next, &JsValue::from_str("value"))
+            .map_err(Error::from)
+            .and_then(|value| Ok(serde_wasm_bindgen::from_value(value)?));
+
+        match line {
+            Ok(line) => Some((Ok(line), Some(lines))),
+            Err(e) => Some((Err(e), None)),
+        }
+    }))
 }
 
-/// Read a file as an UTF-8 encoded string.
+/// Closes the backend resource of a [`read_text_file_lines`] stream dropped before the last line.
+struct Lines(inner::LinesIterator);
+
+impl Drop for Lines {
+    fn drop(&mut self) {
+        let rid = self.0.rid();
+
+        if !rid.is_null() && !rid.is_undefined() {
+            let _ = inner::Resource::new(rid).close();
+        }
+    }
+}
+
+/// Removes a file or directory.
+///
+/// Unless `options.recursive` is set, the call fails for non-empty directories.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, RemoveOptions};
+/// use std::path::Path;
 ///
-/// let contents = fs::readTextFile(path, BaseDirectory::Download).expect("could not read file as text");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// fs::remove(
+///     Path::new("users"),
+///     &RemoveOptions {
+///         base_dir: Some(BaseDirectory::AppLocalData),
+///         recursive: true,
+///     },
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > readTextFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn read_text_file(path: &Path, dir: BaseDirectory) -> crate::Result<String> {
+/// Requires [`plugins > fs > remove`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn remove(path: &Path, options: &RemoveOptions) -> crate::Result<()> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    let raw = inner::readTextFile(
-        path,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
-    )
-    .await?;
-
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    Ok(inner::remove(path, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
-/// Removes a directory.
-/// If the directory is not empty the promise will be rejected.
+/// Renames (moves) a file or directory, replacing `new_path` if it exists.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, RenameOptions};
+/// use std::path::Path;
 ///
-/// fs::remove_dir(path, BaseDirectory::Download).expect("could not remove directory");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// fs::rename(
+///     Path::new("avatar.png"),
+///     Path::new("deleted.png"),
+///     &RenameOptions {
+///         old_path_base_dir: Some(BaseDirectory::AppData),
+///         new_path_base_dir: Some(BaseDirectory::AppLocalData),
+///     },
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > removeDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn remove_dir(dir: &Path, base_dir: BaseDirectory) -> crate::Result<()> {
-    let recursive = Some(false);
-    let Some(dir) = dir.to_str() else {
-        return Err(Error::Utf8(dir.to_path_buf()));
+/// Requires [`plugins > fs > rename`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn rename(
+    old_path: &Path,
+    new_path: &Path,
+    options: &RenameOptions,
+) -> crate::Result<()> {
+    let Some(old_path) = old_path.to_str() else {
+        return Err(Error::Utf8(old_path.to_path_buf()));
     };
 
-    Ok(inner::removeDir(
-        dir,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(base_dir),
-            recursive,
-        })?,
-    )
-    .await?)
+    let Some(new_path) = new_path.to_str() else {
+        return Err(Error::Utf8(new_path.to_path_buf()));
+    };
+
+    Ok(inner::rename(old_path, new_path, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
-/// Removes a directory and its contents.
+/// Gets the metadata of a file or directory, following symlinks.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppData) };
+/// let info = fs::stat(Path::new("avatar.png"), &options).await?;
 ///
-/// fs::remove_dir_all(path, BaseDirectory::Download).expect("could not remove directory");
+/// println!("{} bytes, modified {:?}", info.size, info.mtime);
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > removeDir`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn remove_dir_all(dir: &Path, base_dir: BaseDirectory) -> crate::Result<()> {
-    let recursive = Some(true);
-    let Some(dir) = dir.to_str() else {
-        return Err(Error::Utf8(dir.to_path_buf()));
+/// Requires [`plugins > fs > stat`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn stat(path: &Path, options: &FsOptions) -> crate::Result<FileInfo> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::removeDir(
-        dir,
-        serde_wasm_bindgen::to_value(&FsDirOptions {
-            dir: Some(base_dir),
-            recursive,
-        })?,
-    )
-    .await?)
+    parse_file_info(inner::stat(path, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
-/// Removes a file.
+/// Gets the metadata of a file or directory, describing a symlink itself instead of its target.
+///
+/// Requires [`plugins > fs > lstat`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn lstat(path: &Path, options: &FsOptions) -> crate::Result<FileInfo> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
+    };
+
+    parse_file_info(inner::lstat(path, serde_wasm_bindgen::to_value(options)?).await?)
+}
+
+/// Truncates or extends a file to `len` bytes, or empties it if `len` is `None`.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, FsOptions};
+/// use std::path::Path;
 ///
-/// fs::remove_file(path, BaseDirectory::Download).expect("could not remove file");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppLog) };
+/// fs::truncate(Path::new("app.log"), None, &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > removeFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn remove_file(file: &Path, dir: BaseDirectory) -> crate::Result<()> {
-    let Some(file) = file.to_str() else {
-        return Err(Error::Utf8(file.to_path_buf()));
+/// Requires [`plugins > fs > truncate`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn truncate(path: &Path, len: Option<u64>, options: &FsOptions) -> crate::Result<()> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::removeFile(
-        file,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
+    Ok(inner::truncate(
+        path,
+        len.map(|len| len as f64),
+        serde_wasm_bindgen::to_value(options)?,
     )
     .await?)
 }
 
-/// Renames a file.
+/// Writes `contents` to a file, replacing its contents unless `options.append` is set.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, WriteFileOptions};
+/// use std::path::Path;
 ///
-/// fs::rename_file(old_path, new_path, BaseDirectory::Download).expect("could not rename file");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = WriteFileOptions {
+///     base_dir: Some(BaseDirectory::AppData),
+///     ..Default::default()
+/// };
+/// fs::write_file(Path::new("data.bin"), [0xde, 0xad, 0xbe, 0xef], &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > renameFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn rename_file(
-    old_path: &Path,
-    new_path: &Path,
-    dir: BaseDirectory,
+/// Requires [`plugins > fs > writeFile`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn write_file(
+    path: &Path,
+    contents: impl AsRef<[u8]>,
+    options: &WriteFileOptions,
 ) -> crate::Result<()> {
-    let Some(old_path) = old_path.to_str() else {
-        return Err(Error::Utf8(old_path.to_path_buf()));
-    };
-
-    let Some(new_path) = new_path.to_str() else {
-        return Err(Error::Utf8(new_path.to_path_buf()));
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::renameFile(
-        old_path,
-        new_path,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
+    Ok(inner::writeFile(
+        path,
+        Uint8Array::from(contents.as_ref()).into(),
+        serde_wasm_bindgen::to_value(options)?,
     )
     .await?)
 }
 
-/// Writes a byte array content to a file.
+/// Writes a UTF-8 text file, replacing its contents unless `options.append` is set.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, WriteFileOptions};
+/// use std::path::Path;
 ///
-/// fs::write_binary_file(path, contents, BaseDirectory::Download).expect("could not writes binary file");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = WriteFileOptions {
+///     base_dir: Some(BaseDirectory::AppLog),
+///     append: true,
+///     ..Default::default()
+/// };
+/// fs::write_text_file(Path::new("app.log"), "started\n", &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > writeBinaryFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn write_binary_file(
+/// Requires [`plugins > fs > writeTextFile`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn write_text_file(
     path: &Path,
-    contents: ArrayBuffer,
-    dir: BaseDirectory,
+    contents: &str,
+    options: &WriteFileOptions,
 ) -> crate::Result<()> {
     let Some(path) = path.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::writeBinaryFile(
-        path,
-        contents,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
-    )
-    .await?)
+    Ok(inner::writeTextFile(path, contents, serde_wasm_bindgen::to_value(options)?).await?)
 }
 
-/// Writes a UTF-8 text file.
+/// Replaces the contents of a file without leaving it partially written if the app crashes.
+///
+/// `contents` is written to a temporary file next to `path`, which is then renamed to `path`.
+/// `options.append` is ignored.
 ///
 /// # Example
 ///
 /// ```rust,no_run
-/// use tauri_wasm::plugin::fs;
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, WriteFileOptions};
+/// use std::path::Path;
 ///
-/// fs::write_text_file(path, contents, BaseDirectory::Download).expect("could not writes binary file");
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = WriteFileOptions {
+///     base_dir: Some(BaseDirectory::AppConfig),
+///     ..Default::default()
+/// };
+/// fs::write_file_atomic(Path::new("settings.json"), br#"{"theme":"dark"}"#, &options).await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
-/// Requires [`plugins > fs > writeTextFile`](https://beta.tauri.app/features/file-system) to be enabled.
-pub async fn write_text_file(path: &Path, contents: &str, dir: BaseDirectory) -> crate::Result<()> {
-    let Some(path) = path.to_str() else {
+/// Requires [`plugins > fs > writeFile`](https://beta.tauri.app/features/file-system),
+/// [`plugins > fs > rename`](https://beta.tauri.app/features/file-system) and
+/// [`plugins > fs > remove`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn write_file_atomic(
+    path: &Path,
+    contents: impl AsRef<[u8]>,
+    options: &WriteFileOptions,
+) -> crate::Result<()> {
+    let Some(file_name) = path.file_name() else {
+        return Err(Error::NoFileName(path.to_path_buf()));
+    };
+    let Some(file_name) = file_name.to_str() else {
         return Err(Error::Utf8(path.to_path_buf()));
     };
 
-    Ok(inner::writeTextFile(
+    let temp_path = path.with_file_name(format!(
+        ".{}.{:08x}.tmp",
+        file_name,
+        (js_sys::Math::random() * u32::MAX as f64) as u32
+    ));
+
+    write_file(
+        &temp_path,
+        contents,
+        &WriteFileOptions {
+            append: false,
+            create: true,
+            create_new: true,
+            ..options.clone()
+        },
+    )
+    .await?;
+
+    let renamed = rename(
+        &temp_path,
         path,
-        &contents,
-        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
+        &RenameOptions {
+            old_path_base_dir: options.base_dir,
+            new_path_base_dir: options.base_dir,
+        },
     )
-    .await?)
+    .await;
+
+    if renamed.is_err() {
+        let _ = remove(
+            &temp_path,
+            &RemoveOptions {
+                base_dir: options.base_dir,
+                recursive: false,
+            },
+        )
+        .await;
+    }
+
+    renamed
+}
+
+/// The kind of an access event.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum AccessKind {
+    Any,
+    Read,
+    Open { mode: AccessMode },
+    Close { mode: AccessMode },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccessMode {
+    Any,
+    Execute,
+    Read,
+    Write,
+    #[serde(other)]
+    Other,
+}
+
+/// The kind of a create or remove event.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum EntryKind {
+    Any,
+    File,
+    Folder,
+    #[serde(other)]
+    Other,
+}
+
+/// The kind of a modify event.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ModifyKind {
+    Any,
+    Data { mode: DataChange },
+    Metadata { mode: MetadataKind },
+    Rename { mode: RenameMode },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum DataChange {
+    Any,
+    Size,
+    Content,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataKind {
+    Any,
+    AccessTime,
+    WriteTime,
+    Permissions,
+    Ownership,
+    Extended,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum RenameMode {
+    Any,
+    To,
+    From,
+    Both,
+    #[serde(other)]
+    Other,
+}
+
+/// What happened to the paths of a [`WatchEvent`].
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum WatchEventKind {
+    /// An event the platform could not classify.
+    Any,
+    Access(AccessKind),
+    Create(EntryKind),
+    Modify(ModifyKind),
+    Remove(EntryKind),
+    Other,
+}
+
+/// A change to watched paths.
+#[derive(Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct WatchEvent {
+    #[serde(rename = "type")]
+    pub kind: WatchEventKind,
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Serialize, Default, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchOptions {
+    pub base_dir: Option<BaseDirectory>,
+    /// Also watches the contents of watched directories.
+    pub recursive: bool,
+    /// How long [`watch`] collects changes before emitting them, defaults to 2 seconds.
+    #[serde(
+        rename = "delayMs",
+        serialize_with = "serialize_millis",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub delay: Option<Duration>,
+}
+
+fn serialize_millis<S: serde::Serializer>(
+    delay: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match delay {
+        Some(delay) => serializer.serialize_some(&(delay.as_millis() as f64)),
+        None => serializer.serialize_none(),
+    }
+}
+
+async fn watch_internal(
+    paths: &[&Path],
+    options: &WatchOptions,
+    immediate: bool,
+) -> crate::Result<impl Stream<Item = WatchEvent>> {
+    let mut raw_paths = Vec::with_capacity(paths.len());
+    for path in paths {
+        let Some(path) = path.to_str() else {
+            return Err(Error::Utf8(path.to_path_buf()));
+        };
+        raw_paths.push(path);
+    }
+
+    let (tx, rx) = mpsc::unbounded::<WatchEvent>();
+
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+        if let Ok(event) = serde_wasm_bindgen::from_value(raw) {
+            let _ = tx.unbounded_send(event);
+        }
+    });
+
+    let raw_paths = serde_wasm_bindgen::to_value(&raw_paths)?;
+    let options = serde_wasm_bindgen::to_value(options)?;
+    let unlisten = if immediate {
+        inner::watchImmediate(raw_paths, &closure, options).await?
+    } else {
+        inner::watch(raw_paths, &closure, options).await?
+    };
+    closure.forget();
+
+    Ok(Listen {
+        rx,
+        unlisten: js_sys::Function::from(unlisten),
+    })
+}
+
+/// Watches files or directories for changes, batching events over `options.delay`.
+///
+/// Events that fail to parse are skipped.
+/// The returned Stream stops watching when dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::fs::{self, BaseDirectory, WatchEventKind, WatchOptions};
+/// use futures::StreamExt;
+/// use std::path::Path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = WatchOptions {
+///     base_dir: Some(BaseDirectory::AppLog),
+///     ..Default::default()
+/// };
+/// let mut events = fs::watch(&[Path::new("app.log")], &options).await?;
+///
+/// while let Some(event) = events.next().await {
+///     if let WatchEventKind::Modify(_) = event.kind {
+///         // reload event.paths
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`plugins > fs > watch`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn watch(
+    paths: &[&Path],
+    options: &WatchOptions,
+) -> crate::Result<impl Stream<Item = WatchEvent>> {
+    watch_internal(paths, options, false).await
+}
+
+/// Watches files or directories for changes, emitting every event as it happens.
+///
+/// `options.delay` is ignored.
+/// The returned Stream stops watching when dropped.
+///
+/// Requires [`plugins > fs > watch`](https://beta.tauri.app/features/file-system) to be enabled.
+pub async fn watch_immediate(
+    paths: &[&Path],
+    options: &WatchOptions,
+) -> crate::Result<impl Stream<Item = WatchEvent>> {
+    watch_internal(paths, options, true).await
+}
+
+/// Options and flags which can be used to configure how a file is opened.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::fs::{BaseDirectory, OpenOptions};
+/// use futures::AsyncWriteExt;
+/// use std::path::Path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut file = OpenOptions::new()
+///     .append(true)
+///     .create(true)
+///     .base_dir(BaseDirectory::AppLog)
+///     .open(Path::new("app.log"))
+///     .await?;
+///
+/// file.write_all(b"started\n").await?;
+/// file.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`plugins > fs > open`](https://beta.tauri.app/features/file-system) to be enabled.
+#[derive(Serialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    truncate: bool,
+    create: bool,
+    create_new: bool,
+    mode: Option<u32>,
+    base_dir: Option<BaseDirectory>,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self {
+            read: true,
+            write: false,
+            append: false,
+            truncate: false,
+            create: false,
+            create_new: false,
+            mode: None,
+            base_dir: None,
+        }
+    }
+}
+
+impl OpenOptions {
+    /// Creates options opening a file for reading.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the option for read access.
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.read = read;
+        self
+    }
+
+    /// Sets the option for write access.
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.write = write;
+        self
+    }
+
+    /// Sets the option to append to the file instead of overwriting it, implies write access.
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.append = append;
+        self
+    }
+
+    /// Sets the option to truncate the file to 0 length when opened, requires write access.
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Sets the option to create the file if it does not exist, requires write or append access.
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.create = create;
+        self
+    }
+
+    /// Sets the option to create a new file, failing if it already exists.
+    pub fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.create_new = create_new;
+        self
+    }
+
+    /// Sets the permissions of a newly created file on Unix.
+    pub fn mode(&mut self, mode: u32) -> &mut Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the directory the path is relative to.
+    pub fn base_dir(&mut self, base_dir: BaseDirectory) -> &mut Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
+    /// Opens the file at `path` with these options.
+    pub async fn open(&self, path: &Path) -> crate::Result<File> {
+        let Some(path) = path.to_str() else {
+            return Err(Error::Utf8(path.to_path_buf()));
+        };
+
+        let raw = inner::open(path, serde_wasm_bindgen::to_value(self)?).await?;
+
+        Ok(File::new(raw.unchecked_into()))
+    }
+}
+
+/// An operation in flight, tagged with its arguments.
+///
+/// A caller may abandon an operation, so a completion is only returned to a call with the same arguments.
+enum Pending {
+    Idle,
+    Read(JsFuture, Uint8Array),
+    Write(JsFuture, Vec<u8>),
+    /// The target is `None` for seeks issued internally.
+    Seek(JsFuture, Option<SeekFrom>),
+    Close(JsFuture),
+}
+
+enum Completed {
+    Read(usize),
+    Write(usize, Vec<u8>),
+    Seek(u64, Option<SeekFrom>),
+    Close,
+}
+
+fn io_error(e: JsValue) -> io::Error {
+    io::Error::other(Error::from(e))
+}
+
+/// An open file, see [`OpenOptions`].
+///
+/// Implements [`AsyncRead`], [`AsyncWrite`] and [`AsyncSeek`], so the combinators of
+/// [`futures::io`] can be used. The file is closed when dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::fs::{BaseDirectory, FsOptions, File};
+/// use futures::AsyncReadExt;
+/// use std::path::Path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FsOptions { base_dir: Some(BaseDirectory::AppData) };
+/// let mut file = File::open(Path::new("data.bin"), &options).await?;
+///
+/// let mut header = [0; 16];
+/// file.read_exact(&mut header).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct File {
+    handle: inner::FileHandle,
+    pending: Pending,
+    /// Bytes read ahead of the caller's position.
+    leftover: Vec<u8>,
+    closed: bool,
+}
+
+impl File {
+    fn new(handle: inner::FileHandle) -> Self {
+        Self {
+            handle,
+            pending: Pending::Idle,
+            leftover: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Opens a file in read-only mode.
+    pub async fn open(path: &Path, options: &FsOptions) -> crate::Result<Self> {
+        let mut open_options = OpenOptions::new();
+        open_options.base_dir = options.base_dir;
+
+        open_options.open(path).await
+    }
+
+    /// Opens a file in write-only mode, creating it or truncating it if it exists.
+    pub async fn create(path: &Path, options: &FsOptions) -> crate::Result<Self> {
+        let Some(path) = path.to_str() else {
+            return Err(Error::Utf8(path.to_path_buf()));
+        };
+
+        let raw = inner::create(path, serde_wasm_bindgen::to_value(options)?).await?;
+
+        Ok(Self::new(raw.unchecked_into()))
+    }
+
+    /// Truncates or extends the file to `len` bytes, or empties it if `len` is `None`.
+    pub async fn truncate(&mut self, len: Option<u64>) -> crate::Result<()> {
+        JsFuture::from(self.handle.truncate(len.map(|len| len as f64))).await?;
+
+        Ok(())
+    }
+
+    /// Gets the metadata of the file.
+    pub async fn stat(&self) -> crate::Result<FileInfo> {
+        parse_file_info(JsFuture::from(self.handle.stat()).await?)
+    }
+
+    /// Drives the pending operation to completion.
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<Completed>>> {
+        // the operation is taken out so the slot is idle again once it settles, even on errors
+        let completed = match mem::replace(&mut self.pending, Pending::Idle) {
+            Pending::Idle => return Poll::Ready(Ok(None)),
+            Pending::Read(mut future, buffer) => {
+                let Poll::Ready(raw) = future.poll_unpin(cx) else {
+                    self.pending = Pending::Read(future, buffer);
+                    return Poll::Pending;
+                };
+                let n = raw.map_err(io_error)?.as_f64().unwrap_or_default() as usize;
+                self.leftover = buffer.subarray(0, n as u32).to_vec();
+
+                Completed::Read(n)
+            }
+            Pending::Write(mut future, data) => {
+                let Poll::Ready(raw) = future.poll_unpin(cx) else {
+                    self.pending = Pending::Write(future, data);
+                    return Poll::Pending;
+                };
+
+                Completed::Write(raw.map_err(io_error)?.as_f64().unwrap_or_default() as usize, data)
+            }
+            Pending::Seek(mut future, target) => {
+                let Poll::Ready(raw) = future.poll_unpin(cx) else {
+                    self.pending = Pending::Seek(future, target);
+                    return Poll::Pending;
+                };
+
+                Completed::Seek(raw.map_err(io_error)?.as_f64().unwrap_or_default() as u64, target)
+            }
+            Pending::Close(mut future) => {
+                let Poll::Ready(raw) = future.poll_unpin(cx) else {
+                    self.pending = Pending::Close(future);
+                    return Poll::Pending;
+                };
+                raw.map_err(io_error)?;
+                self.closed = true;
+
+                Completed::Close
+            }
+        };
+
+        Poll::Ready(Ok(Some(completed)))
+    }
+
+    fn start_seek(&mut self, pos: SeekFrom, target: Option<SeekFrom>) {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset as f64, 0),
+            // the handle is ahead of the caller by the bytes read ahead
+            SeekFrom::Current(offset) => ((offset - self.leftover.len() as i64) as f64, 1),
+            SeekFrom::End(offset) => (offset as f64, 2),
+        };
+        self.leftover.clear();
+
+        self.pending = Pending::Seek(JsFuture::from(self.handle.seek(offset, whence)), target);
+    }
+
+    fn check_open(&self) -> io::Result<()> {
+        if self.closed {
+            return Err(io::Error::new(io::ErrorKind::NotConnected, "file is closed"));
+        }
+
+        Ok(())
+    }
+}
+
+impl AsyncRead for File {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            if !this.leftover.is_empty() {
+                let n = buf.len().min(this.leftover.len());
+                buf[..n].copy_from_slice(&this.leftover[..n]);
+                this.leftover.drain(..n);
+
+                return Poll::Ready(Ok(n));
+            }
+
+            match ready!(this.poll_pending(cx))? {
+                Some(Completed::Read(0)) => return Poll::Ready(Ok(0)),
+                Some(_) => continue,
+                None => {
+                    this.check_open()?;
+
+                    if buf.is_empty() {
+                        return Poll::Ready(Ok(0));
+                    }
+
+                    let buffer = Uint8Array::new_with_length(buf.len() as u32);
+                    let future = JsFuture::from(this.handle.read(&buffer));
+                    this.pending = Pending::Read(future, buffer);
+                }
+            }
+        }
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            match ready!(this.poll_pending(cx))? {
+                Some(Completed::Write(n, data)) if data == buf => return Poll::Ready(Ok(n)),
+                Some(_) => continue,
+                None => {
+                    this.check_open()?;
+
+                    if !this.leftover.is_empty() {
+                        // rewind to the caller's position before writing
+                        this.start_seek(SeekFrom::Current(0), None);
+                        continue;
+                    }
+
+                    if buf.is_empty() {
+                        return Poll::Ready(Ok(0));
+                    }
+
+                    let future = JsFuture::from(this.handle.write(&Uint8Array::from(buf)));
+                    this.pending = Pending::Write(future, buf.to_vec());
+                }
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // writes are not buffered, only the pending operation has to finish
+        ready!(self.get_mut().poll_pending(cx))?;
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            match ready!(this.poll_pending(cx))? {
+                Some(Completed::Close) => return Poll::Ready(Ok(())),
+                Some(_) => continue,
+                None if this.closed => return Poll::Ready(Ok(())),
+                None => this.pending = Pending::Close(JsFuture::from(this.handle.close())),
+            }
+        }
+    }
+}
+
+impl AsyncSeek for File {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = self.get_mut();
+
+        loop {
+            match ready!(this.poll_pending(cx))? {
+                Some(Completed::Seek(offset, Some(target))) if target == pos => {
+                    return Poll::Ready(Ok(offset));
+                }
+                Some(_) => continue,
+                None => {
+                    this.check_open()?;
+                    this.start_seek(pos, Some(pos));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        if !self.closed && !matches!(self.pending, Pending::Close(_)) {
+            let _ = self.handle.close();
+        }
+    }
 }
 
 mod inner {
-    use super::ArrayBuffer;
-    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
 
     #[wasm_bindgen(module = "/src/scripts/plugins/fs.js")]
     extern "C" {
         #[wasm_bindgen(catch)]
         pub async fn copyFile(
-            source: &str,
-            destination: &str,
+            fromPath: &str,
+            toPath: &str,
             options: JsValue,
-        ) -> Result<JsValue, JsValue>;
+        ) -> Result<(), JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn createDir(dir: &str, options: JsValue) -> Result<(), JsValue>;
+        pub async fn mkdir(path: &str, options: JsValue) -> Result<(), JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn exists(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn readBinaryFile(filePath: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        pub async fn readFile(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn readTextFile(filePath: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        pub async fn readTextFile(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn readDir(dir: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        pub async fn readDir(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn removeDir(dir: &str, options: JsValue) -> Result<(), JsValue>;
+        pub async fn remove(path: &str, options: JsValue) -> Result<(), JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn removeFile(source: &str, options: JsValue) -> Result<(), JsValue>;
+        pub async fn rename(oldPath: &str, newPath: &str, options: JsValue) -> Result<(), JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn renameFile(
-            oldPath: &str,
-            newPath: &str,
+        pub async fn truncate(
+            path: &str,
+            len: Option<f64>,
             options: JsValue,
         ) -> Result<(), JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn writeBinaryFile(
-            filePath: &str,
-            contents: ArrayBuffer,
+        pub async fn writeFile(path: &str, data: JsValue, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn writeTextFile(path: &str, data: &str, options: JsValue)
+        -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn stat(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn lstat(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn readTextFileLines(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn watch(
+            paths: JsValue,
+            cb: &Closure<dyn FnMut(JsValue)>,
             options: JsValue,
-        ) -> Result<(), JsValue>;
+        ) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
-        pub async fn writeTextFile(
-            filePath: &str,
-            contents: &str,
+        pub async fn watchImmediate(
+            paths: JsValue,
+            cb: &Closure<dyn FnMut(JsValue)>,
             options: JsValue,
-        ) -> Result<(), JsValue>;
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn open(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn create(path: &str, options: JsValue) -> Result<JsValue, JsValue>;
+    }
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/fs.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type FileHandle;
+        #[wasm_bindgen(method)]
+        pub fn read(this: &FileHandle, buffer: &js_sys::Uint8Array) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn write(this: &FileHandle, data: &js_sys::Uint8Array) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn seek(this: &FileHandle, offset: f64, whence: u8) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn truncate(this: &FileHandle, len: Option<f64>) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn stat(this: &FileHandle) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn close(this: &FileHandle) -> js_sys::Promise;
+    }
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// The async iterator returned by `readTextFileLines`.
+        #[derive(Debug, Clone)]
+        pub type LinesIterator;
+        #[wasm_bindgen(method)]
+        pub fn next(this: &LinesIterator) -> js_sys::Promise;
+        /// The backend resource, `null` until the file is opened and after the last line.
+        #[wasm_bindgen(method, getter)]
+        pub fn rid(this: &LinesIterator) -> JsValue;
+        #[wasm_bindgen(method, setter)]
+        pub fn set_rid(this: &LinesIterator, rid: &JsValue);
     }
-}
\ No newline at end of file
+
+    #[wasm_bindgen(module = "/src/scripts/api/core.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+        pub type Resource;
+        #[wasm_bindgen(constructor)]
+        pub fn new(rid: JsValue) -> Resource;
+        #[wasm_bindgen(method)]
+        pub fn close(this: &Resource) -> js_sys::Promise;
+    }
+}
@@ -1,7 +1,228 @@
-//! 
+//!
 //! Version: **geolocation-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-geolocation](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/geolocation)
 //!
 
-mod base {}
\ No newline at end of file
+use futures::{channel::mpsc, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{prelude::Closure, JsValue};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PermissionState {
+    #[serde(rename = "granted")]
+    Granted,
+    #[serde(rename = "denied")]
+    Denied,
+    #[serde(rename = "prompt")]
+    Prompt,
+    #[serde(rename = "prompt-with-rationale")]
+    PromptWithRationale,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PermissionType {
+    #[serde(rename = "location")]
+    Location,
+    #[serde(rename = "coarseLocation")]
+    CoarseLocation,
+}
+
+/// The permission state of each [`PermissionType`].
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionStatus {
+    /// Permission state for the location alias.
+    ///
+    /// On Android it requests/checks both ACCESS_COARSE_LOCATION and ACCESS_FINE_LOCATION permissions.
+    pub location: PermissionState,
+    /// Permission state for the coarseLocation alias.
+    ///
+    /// On Android it requests/checks ACCESS_COARSE_LOCATION.
+    /// On iOS it will have the same value as the `location` alias.
+    pub coarse_location: PermissionState,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Coordinates {
+    /// Latitude in decimal degrees.
+    pub latitude: f64,
+    /// Longitude in decimal degrees.
+    pub longitude: f64,
+    /// Accuracy level of the latitude and longitude coordinates in meters.
+    pub accuracy: f64,
+    /// Accuracy level of the altitude coordinate in meters, if available.
+    pub altitude_accuracy: Option<f64>,
+    /// The altitude the user is at, if available.
+    pub altitude: Option<f64>,
+    /// The speed the user is traveling in meters per second, if available.
+    pub speed: Option<f64>,
+    /// The heading the user is facing, if available.
+    pub heading: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct Position {
+    /// Creation time for these coordinates, in milliseconds since the Unix epoch.
+    pub timestamp: f64,
+    /// The GPS coordinates along with the accuracy of the data.
+    pub coords: Coordinates,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionOptions {
+    /// High accuracy mode, such as GPS if available.
+    pub enable_high_accuracy: bool,
+    /// The maximum wait time in milliseconds for location updates.
+    pub timeout: u32,
+    /// The maximum age in milliseconds of a possible cached position that is acceptable to return.
+    pub maximum_age: u32,
+}
+
+impl Default for PositionOptions {
+    /// The plugin's defaults, a 10 second timeout and no cached positions.
+    fn default() -> Self {
+        Self {
+            enable_high_accuracy: false,
+            timeout: 10_000,
+            maximum_age: 0,
+        }
+    }
+}
+
+/// Checks the location permissions.
+#[inline(always)]
+pub async fn check_permissions() -> crate::Result<PermissionStatus> {
+    let raw = base::checkPermissions().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Requests the given location permissions, or all of them if `permissions` is `None`.
+#[inline(always)]
+pub async fn request_permissions(
+    permissions: Option<&[PermissionType]>,
+) -> crate::Result<PermissionStatus> {
+    let raw = base::requestPermissions(serde_wasm_bindgen::to_value(&permissions)?).await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Gets the current position of the device.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::geolocation::{get_current_position, PositionOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let position = get_current_position(PositionOptions::default()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn get_current_position(options: PositionOptions) -> crate::Result<Position> {
+    let raw = base::getCurrentPosition(serde_wasm_bindgen::to_value(&options)?).await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Watches the position of the device.
+///
+/// The returned Stream will automatically clear the watch when dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::geolocation::{watch_position, PositionOptions};
+/// use futures::StreamExt;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut positions = watch_position(PositionOptions {
+///     enable_high_accuracy: true,
+///     ..Default::default()
+/// })
+/// .await?;
+///
+/// while let Some(position) = positions.next().await {
+///     let position = position?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn watch_position(
+    options: PositionOptions,
+) -> crate::Result<impl Stream<Item = crate::Result<Position>>> {
+    let (tx, rx) = mpsc::unbounded::<crate::Result<Position>>();
+
+    let closure = Closure::<dyn FnMut(JsValue, JsValue)>::new(move |raw: JsValue, error: JsValue| {
+        let msg = match error.as_string() {
+            Some(error) => Err(crate::Error::Command(error)),
+            None => serde_wasm_bindgen::from_value(raw).map_err(Into::into),
+        };
+
+        let _ = tx.unbounded_send(msg);
+    });
+    let channel_id = base::watchPosition(serde_wasm_bindgen::to_value(&options)?, &closure).await?;
+
+    Ok(Watch {
+        rx,
+        channel_id,
+        closure: Some(closure),
+    })
+}
+
+struct Watch<T> {
+    rx: mpsc::UnboundedReceiver<T>,
+    channel_id: JsValue,
+    closure: Option<Closure<dyn FnMut(JsValue, JsValue)>>,
+}
+
+impl<T> Drop for Watch<T> {
+    fn drop(&mut self) {
+        let promise = base::clearWatch(self.channel_id.clone());
+        let closure = self.closure.take();
+
+        // Positions may still arrive until the watch is cleared
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+            drop(closure);
+        });
+    }
+}
+
+impl<T> Stream for Watch<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+mod base {
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/geolocation.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn checkPermissions() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn requestPermissions(permissions: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn getCurrentPosition(options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn watchPosition(
+            options: JsValue,
+            cb: &Closure<dyn FnMut(JsValue, JsValue)>,
+        ) -> Result<JsValue, JsValue>;
+        pub fn clearWatch(channelId: JsValue) -> js_sys::Promise;
+    }
+}
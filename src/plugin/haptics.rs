@@ -1,7 +1,123 @@
-//! 
+//!
 //! Version: **haptics-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-haptics](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/haptics)
 //!
 
-mod base {}
\ No newline at end of file
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+/// The style of an impact feedback.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ImpactFeedbackStyle {
+    #[serde(rename = "light")]
+    Light,
+    #[serde(rename = "medium")]
+    Medium,
+    #[serde(rename = "heavy")]
+    Heavy,
+    #[serde(rename = "soft")]
+    Soft,
+    #[serde(rename = "rigid")]
+    Rigid,
+}
+
+/// The type of a notification feedback.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NotificationFeedbackType {
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "error")]
+    Error,
+}
+
+/// The guest-js resolves to `{ status: "error", error }` instead of rejecting.
+fn into_result(raw: JsValue) -> crate::Result<()> {
+    let status = js_sys::Reflect::get(&raw, &JsValue::from_str("status"))?;
+
+    if status.as_string().as_deref() == Some("error") {
+        let error = js_sys::Reflect::get(&raw, &JsValue::from_str("error"))?;
+
+        return Err(match error.as_string() {
+            Some(error) => crate::Error::Command(error),
+            None => error.into(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Vibrates the device for `duration` milliseconds.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::haptics::vibrate;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// vibrate(200).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn vibrate(duration: u32) -> crate::Result<()> {
+    into_result(base::vibrate(duration).await?)
+}
+
+/// Triggers an impact feedback.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::haptics::{impact_feedback, ImpactFeedbackStyle};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// impact_feedback(ImpactFeedbackStyle::Medium).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn impact_feedback(style: ImpactFeedbackStyle) -> crate::Result<()> {
+    into_result(base::impactFeedback(serde_wasm_bindgen::to_value(&style)?).await?)
+}
+
+/// Triggers a notification feedback.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::haptics::{notification_feedback, NotificationFeedbackType};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// notification_feedback(NotificationFeedbackType::Success).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn notification_feedback(kind: NotificationFeedbackType) -> crate::Result<()> {
+    into_result(base::notificationFeedback(serde_wasm_bindgen::to_value(&kind)?).await?)
+}
+
+/// Triggers a selection feedback.
+#[inline(always)]
+pub async fn selection_feedback() -> crate::Result<()> {
+    into_result(base::selectionFeedback().await?)
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/haptics.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn vibrate(duration: u32) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn impactFeedback(style: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn notificationFeedback(kind: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn selectionFeedback() -> Result<JsValue, JsValue>;
+    }
+}
@@ -1,48 +1,315 @@
-//! 
+//!
 //! Version: **http-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-http](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/http)
 //!
+//! Request bodies are sent to the backend in a single `fetch` call, the plugin does not report upload progress.
+//!
+
+use bytes::Bytes;
+use futures::{stream, Stream};
+use js_sys::{Array, Uint8Array};
+use serde::Serialize;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
 
 /// Configuration of a proxy that a Client should pass requests to.
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct Proxy {
     /// Proxy all traffic to the passed URL.
-    all : Option<String>,
+    pub all : Option<ProxyConfig>,
     /// Proxy all HTTP traffic to the passed URL.
-    http : Option<String>,
+    pub http : Option<ProxyConfig>,
     /// Proxy all HTTPS traffic to the passed URL.
-    https : Option<String>,
+    pub https : Option<ProxyConfig>,
 }
 
 
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct BasicAuth {
-    username: String,
-    password: String,
+    pub username: String,
+    pub password: String,
 }
 
 
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ProxyConfig {
     /// The URL of the proxy server.
-    url: String,
+    pub url: String,
     /// Set the `Proxy-Authorization` header using Basic auth.
-    basic_auth: Option<BasicAuth>,
+    pub basic_auth: Option<BasicAuth>,
     /// A configuration for filtering out requests that shouldn’t be proxied.
     /// Entries are expected to be comma-separated (whitespace between entries is ignored)
-    no_proxy: Option<String>,
+    pub no_proxy: Option<String>,
 }
 
 
 /// Options to configure the Rust client used to make fetch requests
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClientOptions {
     /// Defines the maximum number of redirects the client should follow.
     /// If set to 0, no redirects will be followed.
-    max_redirections: Option<usize>,
+    pub max_redirections: Option<usize>,
     /// Timeout in milliseconds
-    connect_timeout: Option<usize>,
+    pub connect_timeout: Option<usize>,
     /// Configuration of a proxy that a Client should pass requests to.
+    pub proxy: Option<Proxy>,
+}
+
+/// The request builder.
+///
+/// Collects method, headers, body and client options for a [`fetch`](RequestBuilder::fetch) call.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestBuilder<'a> {
+    method: Option<&'a str>,
+    headers: Vec<(&'a str, &'a str)>,
+    #[serde(skip)]
+    body: Option<&'a [u8]>,
+    max_redirections: Option<usize>,
+    connect_timeout: Option<usize>,
     proxy: Option<Proxy>,
-}
\ No newline at end of file
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Gets the default request builder, a `GET` request without body.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the HTTP method of the request.
+    pub fn set_method(&mut self, method: &'a str) -> &mut Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Add a header to the request.
+    pub fn add_header(&mut self, name: &'a str, value: &'a str) -> &mut Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// Set the request body.
+    pub fn set_body(&mut self, body: &'a [u8]) -> &mut Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Set the options of the Rust client making the request.
+    pub fn set_client_options(&mut self, client: ClientOptions) -> &mut Self {
+        self.max_redirections = client.max_redirections;
+        self.connect_timeout = client.connect_timeout;
+        self.proxy = client.proxy;
+        self
+    }
+
+    /// Sends the request to `url`.
+    ///
+    /// The returned [`Response`] does not buffer the body, use [`Response::bytes_stream`] to read it chunk by chunk.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::plugin::http::RequestBuilder;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let res = RequestBuilder::new()
+    ///     .set_method("POST")
+    ///     .add_header("Content-Type", "application/json")
+    ///     .set_body(br#"{ "name": "tauri" }"#)
+    ///     .fetch("https://example.com/api")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires [`plugins > http > fetch`](https://beta.tauri.app/features/http) to be enabled.
+    pub async fn fetch(&self, url: &str) -> crate::Result<Response> {
+        let init = serde_wasm_bindgen::to_value(&self)?;
+
+        if let Some(body) = self.body {
+            js_sys::Reflect::set(&init, &JsValue::from_str("body"), &Uint8Array::from(body))?;
+        }
+
+        let raw = base::fetch(url, init).await?;
+
+        Ok(Response(raw.unchecked_into()))
+    }
+}
+
+/// Sends a `GET` request to `url`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::http::fetch;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let body = fetch("https://example.com").await?.text().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn fetch(url: &str) -> crate::Result<Response> {
+    RequestBuilder::new().fetch(url).await
+}
+
+/// The response of a [`fetch`] call.
+#[derive(Debug, Clone)]
+pub struct Response(base::Response);
+
+impl Response {
+    /// The status code of the response.
+    pub fn status(&self) -> u16 {
+        self.0.status()
+    }
+
+    /// The status message of the response.
+    pub fn status_text(&self) -> String {
+        self.0.status_text()
+    }
+
+    /// Whether the status code is in the range 200-299.
+    pub fn ok(&self) -> bool {
+        self.0.ok()
+    }
+
+    /// The final URL of the response, after redirects.
+    pub fn url(&self) -> String {
+        self.0.url()
+    }
+
+    /// The response headers as name/value pairs.
+    pub fn headers(&self) -> Vec<(String, String)> {
+        Array::from(&self.0.headers())
+            .iter()
+            .filter_map(|raw| serde_wasm_bindgen::from_value(raw).ok())
+            .collect()
+    }
+
+    /// Reads the whole body into memory.
+    pub async fn bytes(self) -> crate::Result<Vec<u8>> {
+        let raw = JsFuture::from(self.0.array_buffer()?).await?;
+
+        Ok(Uint8Array::new(&raw).to_vec())
+    }
+
+    /// Reads the whole body into memory as an UTF-8 string.
+    pub async fn text(self) -> crate::Result<String> {
+        let raw = JsFuture::from(self.0.text()?).await?;
+
+        Ok(serde_wasm_bindgen::from_value(raw)?)
+    }
+
+    /// Streams the body chunk by chunk.
+    ///
+    /// Every chunk is read from the backend on demand, so the body never has to be held in memory at once.
+    /// Dropping the stream before the end cancels the body on the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::plugin::http::fetch;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut chunks = fetch("https://example.com/large.bin").await?.bytes_stream();
+    ///
+    /// while let Some(chunk) = chunks.next().await {
+    ///     let chunk = chunk?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bytes_stream(self) -> impl Stream<Item = crate::Result<Bytes>> {
+        let reader = self.0.body().map(|body| BodyReader {
+            reader: body.get_reader(),
+            done: false,
+        });
+
+        stream::unfold(reader, |reader| async move {
+            let mut reader = reader?;
+
+            let chunk = match JsFuture::from(reader.reader.read()).await {
+                Ok(chunk) => chunk,
+                Err(e) => return Some((Err(e.into()), None)),
+            };
+
+            let done = js_sys::Reflect::get(&chunk, &JsValue::from_str("done"))
+                .map(|done| done.is_truthy())
+                .unwrap_or(true);
+
+            if done {
+                reader.done = true;
+                return None;
+            }
+
+            let value = match js_sys::Reflect::get(&chunk, &JsValue::from_str("value")) {
+                Ok(value) => value,
+                Err(e) => return Some((Err(e.into()), None)),
+            };
+
+            Some((Ok(Bytes::from(Uint8Array::new(&value).to_vec())), Some(reader)))
+        })
+    }
+}
+
+struct BodyReader {
+    reader: base::ReadableStreamDefaultReader,
+    done: bool,
+}
+
+impl Drop for BodyReader {
+    fn drop(&mut self) {
+        if !self.done {
+            let _ = self.reader.cancel();
+        }
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/http.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn fetch(input: &str, init: JsValue) -> Result<JsValue, JsValue>;
+    }
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Response;
+        #[wasm_bindgen(method, getter)]
+        pub fn status(this: &Response) -> u16;
+        #[wasm_bindgen(method, getter, js_name = statusText)]
+        pub fn status_text(this: &Response) -> String;
+        #[wasm_bindgen(method, getter)]
+        pub fn ok(this: &Response) -> bool;
+        #[wasm_bindgen(method, getter)]
+        pub fn url(this: &Response) -> String;
+        #[wasm_bindgen(method, getter)]
+        pub fn headers(this: &Response) -> JsValue;
+        #[wasm_bindgen(method, getter)]
+        pub fn body(this: &Response) -> Option<ReadableStream>;
+        #[wasm_bindgen(method, catch, js_name = arrayBuffer)]
+        pub fn array_buffer(this: &Response) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub fn text(this: &Response) -> Result<js_sys::Promise, JsValue>;
+    }
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type ReadableStream;
+        #[wasm_bindgen(method, js_name = getReader)]
+        pub fn get_reader(this: &ReadableStream) -> ReadableStreamDefaultReader;
+
+        pub type ReadableStreamDefaultReader;
+        #[wasm_bindgen(method)]
+        pub fn read(this: &ReadableStreamDefaultReader) -> js_sys::Promise;
+        #[wasm_bindgen(method)]
+        pub fn cancel(this: &ReadableStreamDefaultReader) -> js_sys::Promise;
+    }
+}
@@ -1,7 +1,353 @@
-//! 
+//!
 //! Version: **log-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-log](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/log)
 //!
+//! Besides the direct functions, [`Logger`] forwards records of the [`log`](https://docs.rs/log) crate
+//! and, with the `tracing` feature, [`LogLayer`] forwards [`tracing`](https://docs.rs/tracing) events
+//! to the backend, so frontend and backend logs end up in the same targets.
+//!
+
+use serde::Serialize;
+use serde_repr::Serialize_repr;
+use std::collections::HashMap;
+
+/// The level of a log record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize_repr)]
+#[repr(u8)]
+pub enum LogLevel {
+    Trace = 1,
+    Debug = 2,
+    Info = 3,
+    Warn = 4,
+    Error = 5,
+}
+
+impl From<::log::Level> for LogLevel {
+    fn from(level: ::log::Level) -> Self {
+        match level {
+            ::log::Level::Trace => LogLevel::Trace,
+            ::log::Level::Debug => LogLevel::Debug,
+            ::log::Level::Info => LogLevel::Info,
+            ::log::Level::Warn => LogLevel::Warn,
+            ::log::Level::Error => LogLevel::Error,
+        }
+    }
+}
+
+/// Additional data of a log record.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogOptions {
+    /// The target of the record, logged by the backend as `webview:{target}`.
+    ///
+    /// Defaults to the location of the caller in the bundled JS.
+    pub target: Option<String>,
+    /// The source file of the record.
+    pub file: Option<String>,
+    /// The source line of the record.
+    pub line: Option<u32>,
+    /// Structured key-value pairs of the record.
+    pub key_values: HashMap<String, String>,
+}
+
+/// The arguments of the `log` command.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogArgs<'a> {
+    level: LogLevel,
+    message: &'a str,
+    location: &'a str,
+    file: Option<&'a str>,
+    line: Option<u32>,
+    key_values: &'a HashMap<String, String>,
+}
+
+/// Logs a message at `level`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::log::{log, LogLevel, LogOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// log(LogLevel::Info, "Window opened", &LogOptions::default()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn log(level: LogLevel, message: &str, options: &LogOptions) -> crate::Result<()> {
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    if let Some(target) = &options.target {
+        // the guest-js derives the location from its call stack, so the command is invoked directly
+        let args = LogArgs {
+            level,
+            message,
+            location: target,
+            file: options.file.as_deref(),
+            line: options.line,
+            key_values: &options.key_values,
+        };
+        base::invoke("plugin:log|log", args.serialize(&serializer)?).await?;
+
+        return Ok(());
+    }
+
+    let options = options.serialize(&serializer)?;
+
+    match level {
+        LogLevel::Trace => base::trace(message, options).await?,
+        LogLevel::Debug => base::debug(message, options).await?,
+        LogLevel::Info => base::info(message, options).await?,
+        LogLevel::Warn => base::warn(message, options).await?,
+        LogLevel::Error => base::error(message, options).await?,
+    }
+
+    Ok(())
+}
+
+/// Logs a message at the trace level.
+#[inline(always)]
+pub async fn trace(message: &str, options: &LogOptions) -> crate::Result<()> {
+    log(LogLevel::Trace, message, options).await
+}
+
+/// Logs a message at the debug level.
+#[inline(always)]
+pub async fn debug(message: &str, options: &LogOptions) -> crate::Result<()> {
+    log(LogLevel::Debug, message, options).await
+}
+
+/// Logs a message at the info level.
+#[inline(always)]
+pub async fn info(message: &str, options: &LogOptions) -> crate::Result<()> {
+    log(LogLevel::Info, message, options).await
+}
+
+/// Logs a message at the warn level.
+#[inline(always)]
+pub async fn warn(message: &str, options: &LogOptions) -> crate::Result<()> {
+    log(LogLevel::Warn, message, options).await
+}
+
+/// Logs a message at the error level.
+#[inline(always)]
+pub async fn error(message: &str, options: &LogOptions) -> crate::Result<()> {
+    log(LogLevel::Error, message, options).await
+}
+
+/// Sends a record from a synchronous context, errors are dropped.
+fn forward(level: LogLevel, message: String, options: LogOptions) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let _ = log(level, &message, &options).await;
+    });
+}
+
+/// Prints the backend logs to the webview console.
+///
+/// The returned guard detaches the console when dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::log::attach_console;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let _console = attach_console().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn attach_console() -> crate::Result<ConsoleGuard> {
+    let unlisten = base::attachConsole().await?;
+
+    Ok(ConsoleGuard {
+        unlisten: js_sys::Function::from(unlisten),
+    })
+}
+
+/// Keeps the console attached, see [`attach_console`].
+pub struct ConsoleGuard {
+    unlisten: js_sys::Function,
+}
+
+impl Drop for ConsoleGuard {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&wasm_bindgen::JsValue::NULL);
+    }
+}
+
+/// A [`log::Log`](::log::Log) implementation forwarding records to the backend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::log::Logger;
+///
+/// Logger::init(log::LevelFilter::Info).expect("logger already set");
+///
+/// log::info!(target: "frontend", "started");
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Logger;
+
+static LOGGER: Logger = Logger;
+
+impl Logger {
+    /// Sets [`Logger`] as the global logger of the [`log`](::log) crate.
+    pub fn init(level: ::log::LevelFilter) -> Result<(), ::log::SetLoggerError> {
+        ::log::set_logger(&LOGGER)?;
+        ::log::set_max_level(level);
+
+        Ok(())
+    }
+}
+
+impl ::log::Log for Logger {
+    fn enabled(&self, _metadata: &::log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &::log::Record) {
+        let mut key_values = KeyValues::default();
+        let _ = record.key_values().visit(&mut key_values);
+
+        forward(
+            record.level().into(),
+            record.args().to_string(),
+            LogOptions {
+                target: Some(record.target().to_string()),
+                file: record.file().map(ToString::to_string),
+                line: record.line(),
+                key_values: key_values.0,
+            },
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(Default)]
+struct KeyValues(HashMap<String, String>);
+
+impl<'kvs> ::log::kv::VisitSource<'kvs> for KeyValues {
+    fn visit_pair(
+        &mut self,
+        key: ::log::kv::Key<'kvs>,
+        value: ::log::kv::Value<'kvs>,
+    ) -> Result<(), ::log::kv::Error> {
+        self.0.insert(key.to_string(), value.to_string());
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub use self::tracing::LogLayer;
+
+#[cfg(feature = "tracing")]
+mod tracing {
+    use super::{forward, LogLevel, LogOptions};
+    use std::collections::HashMap;
+    use tracing_core::{
+        field::{Field, Visit},
+        Event, Level, Subscriber,
+    };
+    use tracing_subscriber::layer::{Context, Layer};
+
+    impl From<&Level> for LogLevel {
+        fn from(level: &Level) -> Self {
+            match *level {
+                Level::TRACE => LogLevel::Trace,
+                Level::DEBUG => LogLevel::Debug,
+                Level::INFO => LogLevel::Info,
+                Level::WARN => LogLevel::Warn,
+                Level::ERROR => LogLevel::Error,
+            }
+        }
+    }
+
+    /// A [`tracing_subscriber::Layer`] forwarding events to the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::plugin::log::LogLayer;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// tracing_subscriber::registry().with(LogLayer).init();
+    /// ```
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct LogLayer;
+
+    impl<S: Subscriber> Layer<S> for LogLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+
+            forward(
+                metadata.level().into(),
+                fields.message,
+                LogOptions {
+                    target: Some(metadata.target().to_string()),
+                    file: metadata.file().map(ToString::to_string),
+                    line: metadata.line(),
+                    key_values: fields.key_values,
+                },
+            );
+        }
+    }
+
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        key_values: HashMap<String, String>,
+    }
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message = value.to_string();
+            } else {
+                self.key_values
+                    .insert(field.name().to_string(), value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = format!("{:?}", value);
+            } else {
+                self.key_values
+                    .insert(field.name().to_string(), format!("{:?}", value));
+            }
+        }
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/log.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn trace(message: &str, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn debug(message: &str, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn info(message: &str, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn warn(message: &str, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn error(message: &str, options: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn attachConsole() -> Result<JsValue, JsValue>;
+    }
 
-mod base {}
\ No newline at end of file
+    #[wasm_bindgen(module = "/src/scripts/api/core.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+    }
+}
@@ -1,7 +1,346 @@
-//! 
+//!
 //! Version: **nfc-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-nfc](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/nfc)
 //!
 
-mod base {}
\ No newline at end of file
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Record type of a well known text record.
+pub const RTD_TEXT: &[u8] = b"T";
+/// Record type of a well known URI record.
+pub const RTD_URI: &[u8] = b"U";
+
+/// URI prefixes abbreviated by the first byte of a URI record payload.
+const URI_PREFIXES: [&str; 36] = [
+    "",
+    "http://www.",
+    "https://www.",
+    "http://",
+    "https://",
+    "tel:",
+    "mailto:",
+    "ftp://anonymous:anonymous@",
+    "ftp://ftp.",
+    "ftps://",
+    "sftp://",
+    "smb://",
+    "nfs://",
+    "ftp://",
+    "dav://",
+    "news:",
+    "telnet://",
+    "imap:",
+    "rtsp://",
+    "urn:",
+    "pop:",
+    "sip:",
+    "sips:",
+    "tftp:",
+    "btspp://",
+    "btl2cap://",
+    "btgoep://",
+    "tcpobex://",
+    "irdaobex://",
+    "file://",
+    "urn:epc:id:",
+    "urn:epc:tag:",
+    "urn:epc:pat:",
+    "urn:epc:raw:",
+    "urn:epc:",
+    "urn:nfc:",
+];
+
+/// The Type Name Format of an NDEF record.
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Tnf {
+    Empty = 0,
+    NfcWellKnown = 1,
+    Media = 2,
+    AbsoluteUri = 3,
+    NfcExternal = 4,
+    Unknown = 5,
+    Unchanged = 6,
+}
+
+/// The technologies a tag may support.
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum TechKind {
+    IsoDep = 0,
+    MifareClassic = 1,
+    MifareUltralight = 2,
+    Ndef = 3,
+    NdefFormatable = 4,
+    NfcA = 5,
+    NfcB = 6,
+    NfcBarcode = 7,
+    NfcF = 8,
+    NfcV = 9,
+}
+
+/// Filters scanned tags by the URI of their first record.
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UriFilter {
+    pub scheme: Option<String>,
+    pub host: Option<String>,
+    pub path_prefix: Option<String>,
+}
+
+/// What to scan for.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ScanKind {
+    /// Scan for tags holding NDEF messages.
+    Ndef {
+        uri: Option<UriFilter>,
+        mime_type: Option<String>,
+        /// Android only, lists of technologies a tag must support.
+        tech_lists: Option<Vec<Vec<TechKind>>>,
+    },
+    /// Scan for any tag.
+    Tag {
+        uri: Option<UriFilter>,
+        mime_type: Option<String>,
+    },
+}
+
+impl ScanKind {
+    /// Scan for any NDEF tag.
+    pub fn ndef() -> Self {
+        Self::Ndef {
+            uri: None,
+            mime_type: None,
+            tech_lists: None,
+        }
+    }
+
+    /// Scan for any tag.
+    pub fn tag() -> Self {
+        Self::Tag {
+            uri: None,
+            mime_type: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanOptions {
+    /// Keep the session alive after the tag was read, required to [`write`] to it.
+    pub keep_session_alive: Option<bool>,
+    /// iOS only, message displayed in the scan UI.
+    pub message: Option<String>,
+    /// iOS only, message displayed after a successful scan.
+    pub success_message: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteOptions {
+    /// Scans for a tag before writing, required unless a session was kept alive.
+    pub kind: Option<ScanKind>,
+    /// iOS only, message displayed in the scan UI.
+    pub message: Option<String>,
+    /// iOS only, message displayed after a successful read.
+    pub successful_read_message: Option<String>,
+    /// iOS only, message displayed after a successful write.
+    pub success_message: Option<String>,
+}
+
+/// An NDEF record to write.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct NfcRecord {
+    pub format: Tnf,
+    pub kind: Vec<u8>,
+    pub id: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+/// Creates a record.
+pub fn record(format: Tnf, kind: &[u8], id: &[u8], payload: &[u8]) -> NfcRecord {
+    NfcRecord {
+        format,
+        kind: kind.to_vec(),
+        id: id.to_vec(),
+        payload: payload.to_vec(),
+    }
+}
+
+/// Creates a well known text record, `language` is an IANA language code such as `en`.
+///
+/// Fails if `language` is longer than 63 bytes, the limit of the record's status byte.
+pub fn text_record(text: &str, id: &[u8], language: &str) -> crate::Result<NfcRecord> {
+    if language.len() > 63 {
+        return Err(crate::Error::NfcLanguage(language.to_owned()));
+    }
+
+    let mut payload = Vec::with_capacity(1 + language.len() + text.len());
+    payload.push(language.len() as u8);
+    payload.extend_from_slice(language.as_bytes());
+    payload.extend_from_slice(text.as_bytes());
+
+    Ok(record(Tnf::NfcWellKnown, RTD_TEXT, id, &payload))
+}
+
+/// Creates a well known URI record, known prefixes such as `https://` are abbreviated.
+pub fn uri_record(uri: &str, id: &[u8]) -> NfcRecord {
+    let code = URI_PREFIXES
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, prefix)| uri.starts_with(*prefix))
+        .max_by_key(|(_, prefix)| prefix.len())
+        .map(|(code, _)| code)
+        .unwrap_or(0);
+
+    let mut payload = vec![code as u8];
+    payload.extend_from_slice(&uri.as_bytes()[URI_PREFIXES[code].len()..]);
+
+    record(Tnf::NfcWellKnown, RTD_URI, id, &payload)
+}
+
+/// Creates a media record holding `payload` of type `mime_type`.
+pub fn mime_record(mime_type: &str, id: &[u8], payload: &[u8]) -> NfcRecord {
+    record(Tnf::Media, mime_type.as_bytes(), id, payload)
+}
+
+/// A record read from a tag.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TagRecord {
+    pub tnf: Tnf,
+    pub kind: Vec<u8>,
+    pub id: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+impl TagRecord {
+    /// Decodes a well known text record into its language code and text.
+    pub fn text(&self) -> Option<(String, String)> {
+        if self.tnf != Tnf::NfcWellKnown || self.kind != RTD_TEXT {
+            return None;
+        }
+
+        let (status, rest) = self.payload.split_first()?;
+        let language_len = (status & 0x3f) as usize;
+
+        if status & 0x80 != 0 || rest.len() < language_len {
+            // UTF-16 text is not supported
+            return None;
+        }
+
+        let (language, text) = rest.split_at(language_len);
+
+        Some((
+            String::from_utf8_lossy(language).into_owned(),
+            String::from_utf8_lossy(text).into_owned(),
+        ))
+    }
+
+    /// Decodes a well known URI record, expanding its abbreviated prefix.
+    pub fn uri(&self) -> Option<String> {
+        if self.tnf != Tnf::NfcWellKnown || self.kind != RTD_URI {
+            return None;
+        }
+
+        let (code, rest) = self.payload.split_first()?;
+        let prefix = URI_PREFIXES.get(*code as usize).unwrap_or(&"");
+
+        Some(format!("{}{}", prefix, String::from_utf8_lossy(rest)))
+    }
+
+    /// The MIME type of a media record.
+    pub fn mime_type(&self) -> Option<String> {
+        if self.tnf != Tnf::Media {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&self.kind).into_owned())
+    }
+}
+
+/// A scanned tag.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct Tag {
+    pub id: Vec<u8>,
+    /// The technologies the tag supports.
+    pub kind: Vec<String>,
+    pub records: Vec<TagRecord>,
+}
+
+/// Checks if NFC is available on the device.
+#[inline(always)]
+pub async fn is_available() -> crate::Result<bool> {
+    let raw = base::isAvailable().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Scans a tag.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::nfc::{scan, ScanKind, ScanOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let tag = scan(ScanKind::ndef(), ScanOptions::default()).await?;
+///
+/// for uri in tag.records.iter().filter_map(|record| record.uri()) {
+///     // open uri
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn scan(kind: ScanKind, options: ScanOptions) -> crate::Result<Tag> {
+    let raw = base::scan(
+        serde_wasm_bindgen::to_value(&kind)?,
+        serde_wasm_bindgen::to_value(&options)?,
+    )
+    .await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+/// Writes NDEF records to a tag.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::nfc::{text_record, uri_record, write, ScanKind, WriteOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let records = [uri_record("https://tauri.app", &[]), text_record("Tauri", &[], "en")?];
+///
+/// write(&records, WriteOptions { kind: Some(ScanKind::ndef()), ..Default::default() }).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn write(records: &[NfcRecord], options: WriteOptions) -> crate::Result<()> {
+    base::write(
+        serde_wasm_bindgen::to_value(records)?,
+        serde_wasm_bindgen::to_value(&options)?,
+    )
+    .await?;
+
+    Ok(())
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/nfc.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn isAvailable() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn scan(kind: JsValue, options: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn write(records: JsValue, options: JsValue) -> Result<(), JsValue>;
+    }
+}
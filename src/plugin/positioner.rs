@@ -1,7 +1,120 @@
-//! 
+//!
 //! Version: **positioner-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-positioner](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/positioner)
 //!
 
-mod base {}
\ No newline at end of file
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Where to move a window, the `Tray*` positions require [`handle_icon_state`] to be called first.
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum WindowPosition {
+    TopLeft = 0,
+    TopRight = 1,
+    BottomLeft = 2,
+    BottomRight = 3,
+    TopCenter = 4,
+    BottomCenter = 5,
+    LeftCenter = 6,
+    RightCenter = 7,
+    Center = 8,
+    TrayLeft = 9,
+    TrayBottomLeft = 10,
+    TrayRight = 11,
+    TrayBottomRight = 12,
+    TrayCenter = 13,
+    TrayBottomCenter = 14,
+}
+
+/// The position of a [`Rect`], as serialized by the backend.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RectPosition {
+    Physical { x: i32, y: i32 },
+    Logical { x: f64, y: f64 },
+}
+
+/// The size of a [`Rect`], as serialized by the backend.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RectSize {
+    Physical { width: u32, height: u32 },
+    Logical { width: f64, height: f64 },
+}
+
+/// The area of the tray icon on screen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Rect {
+    pub position: RectPosition,
+    pub size: RectSize,
+}
+
+/// The part of a tray icon event used for positioning, any other fields of the event payload are ignored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TrayIconEvent {
+    pub rect: Rect,
+}
+
+/// Moves the current window to `to`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::positioner::{move_window, WindowPosition};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// move_window(WindowPosition::TopRight).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn move_window(to: WindowPosition) -> crate::Result<()> {
+    base::moveWindow(to as u8).await?;
+
+    Ok(())
+}
+
+/// Moves the current window to `to`, keeping it within the bounds of its monitor.
+#[inline(always)]
+pub async fn move_window_constrained(to: WindowPosition) -> crate::Result<()> {
+    base::moveWindowConstrained(to as u8).await?;
+
+    Ok(())
+}
+
+/// Stores the tray icon area, so windows can be placed relative to it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::positioner::{handle_icon_state, move_window, TrayIconEvent, WindowPosition};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let payload = wasm_bindgen::JsValue::UNDEFINED;
+/// let event: TrayIconEvent = serde_wasm_bindgen::from_value(payload)?;
+///
+/// handle_icon_state(&event).await?;
+/// move_window(WindowPosition::TrayBottomCenter).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn handle_icon_state(event: &TrayIconEvent) -> crate::Result<()> {
+    base::handleIconState(serde_wasm_bindgen::to_value(event)?).await?;
+
+    Ok(())
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/positioner.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn moveWindow(to: u8) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn moveWindowConstrained(to: u8) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn handleIconState(event: JsValue) -> Result<(), JsValue>;
+    }
+}
@@ -1,7 +1,346 @@
-//! 
+//!
 //! Version: **stronghold-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-stronghold](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/stronghold)
 //!
+//! Secrets returned by this module are wrapped in [`Zeroizing`] and the JS buffers they were copied from are overwritten.
+//!
+
+use crate::Error;
+use js_sys::Uint8Array;
+use serde::Serialize;
+use std::{path::Path, time::Duration};
+use wasm_bindgen::{JsCast, JsValue};
+use zeroize::Zeroizing;
+
+/// The location of a record in a vault.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum Location {
+    Generic { vault: String, record: String },
+    Counter { vault: String, counter: usize },
+}
+
+impl Location {
+    pub fn generic(vault: &str, record: &str) -> Self {
+        Self::Generic {
+            vault: vault.to_string(),
+            record: record.to_string(),
+        }
+    }
+
+    pub fn counter(vault: &str, counter: usize) -> Self {
+        Self::Counter {
+            vault: vault.to_string(),
+            counter,
+        }
+    }
+}
+
+/// The source of a SLIP10 derivation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Slip10DeriveInput {
+    Seed,
+    Key,
+}
+
+/// Copies a JS byte array and overwrites the JS side.
+fn take_secret(raw: JsValue) -> Zeroizing<Vec<u8>> {
+    let buf = Uint8Array::new(&raw);
+    let secret = Zeroizing::new(buf.to_vec());
+    buf.fill(0, 0, buf.length());
+
+    secret
+}
+
+fn take_optional_secret(raw: JsValue) -> Option<Zeroizing<Vec<u8>>> {
+    if raw.is_null() || raw.is_undefined() {
+        None
+    } else {
+        Some(take_secret(raw))
+    }
+}
+
+/// A stronghold snapshot.
+#[derive(Debug, Clone)]
+pub struct Stronghold(base::Stronghold);
+
+impl Stronghold {
+    /// Loads the snapshot at `path` if it exists, the password must match. Otherwise a fresh stronghold is started.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::plugin::stronghold::Stronghold;
+    /// use tauri_wasm::api::path::app_data_dir;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let path = app_data_dir().await?.join("vault.hold");
+    /// let stronghold = Stronghold::load(&path, "password").await?;
+    /// let client = stronghold.create_client("main").await?;
+    ///
+    /// client.get_store().insert("token", b"secret", None).await?;
+    /// stronghold.save().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load(path: &Path, password: &str) -> crate::Result<Self> {
+        let Some(path) = path.to_str() else {
+            return Err(Error::Utf8(path.to_path_buf()));
+        };
+
+        let raw = base::Stronghold::load(path, password).await?;
+
+        Ok(Self(raw.unchecked_into()))
+    }
+
+    /// Removes this instance from the backend cache.
+    pub async fn unload(&self) -> crate::Result<()> {
+        self.0.unload().await?;
+
+        Ok(())
+    }
+
+    /// Loads an existing client.
+    pub async fn load_client(&self, client: &str) -> crate::Result<Client> {
+        let raw = self.0.loadClient(client).await?;
+
+        Ok(Client(raw.unchecked_into()))
+    }
+
+    /// Creates a new client.
+    pub async fn create_client(&self, client: &str) -> crate::Result<Client> {
+        let raw = self.0.createClient(client).await?;
+
+        Ok(Client(raw.unchecked_into()))
+    }
+
+    /// Persists the stronghold state to the snapshot.
+    pub async fn save(&self) -> crate::Result<()> {
+        self.0.save().await?;
+
+        Ok(())
+    }
+}
+
+/// A stronghold client, holding a key-value store and vaults.
+#[derive(Debug, Clone)]
+pub struct Client(base::Client);
+
+impl Client {
+    /// Gets the key-value store of the client.
+    pub fn get_store(&self) -> Store {
+        Store(self.0.getStore())
+    }
+
+    /// Gets a vault by name.
+    pub fn get_vault(&self, name: &str) -> Vault {
+        Vault(self.0.getVault(name))
+    }
+}
+
+/// The key-value store of a [`Client`].
+#[derive(Debug, Clone)]
+pub struct Store(base::Store);
+
+impl Store {
+    /// Gets the value of `key`.
+    pub async fn get(&self, key: &str) -> crate::Result<Option<Zeroizing<Vec<u8>>>> {
+        let raw = self.0.get(key).await?;
+
+        Ok(take_optional_secret(raw))
+    }
+
+    /// Inserts `value` at `key`, optionally expiring after `lifetime`.
+    pub async fn insert(
+        &self,
+        key: &str,
+        value: &[u8],
+        lifetime: Option<Duration>,
+    ) -> crate::Result<()> {
+        self.0
+            .insert(
+                key,
+                serde_wasm_bindgen::to_value(value)?,
+                serde_wasm_bindgen::to_value(&lifetime)?,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes `key`, returning its value.
+    pub async fn remove(&self, key: &str) -> crate::Result<Option<Zeroizing<Vec<u8>>>> {
+        let raw = self.0.remove(key).await?;
+
+        Ok(take_optional_secret(raw))
+    }
+}
+
+/// A vault of a [`Client`], storing secrets that never leave the backend unless a procedure returns them.
+#[derive(Debug, Clone)]
+pub struct Vault(base::Vault);
+
+impl Vault {
+    /// Inserts a secret at `record_path`.
+    pub async fn insert(&self, record_path: &str, secret: &[u8]) -> crate::Result<()> {
+        self.0
+            .insert(record_path, serde_wasm_bindgen::to_value(secret)?)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes the record at `record_path`.
+    pub async fn remove(&self, record_path: &str) -> crate::Result<()> {
+        let location = Location::generic(&self.0.name(), record_path);
+
+        self.0
+            .remove(serde_wasm_bindgen::to_value(&location)?)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Generates a SLIP10 seed of `size_bytes` bytes, stored at `output`.
+    pub async fn generate_slip10_seed(
+        &self,
+        output: &Location,
+        size_bytes: Option<u32>,
+    ) -> crate::Result<Zeroizing<Vec<u8>>> {
+        let raw = self
+            .0
+            .generateSLIP10Seed(serde_wasm_bindgen::to_value(output)?, size_bytes)
+            .await?;
+
+        Ok(take_secret(raw))
+    }
+
+    /// Derives a SLIP10 private key along `chain` from the seed or key at `source`, stored at `output`.
+    pub async fn derive_slip10(
+        &self,
+        chain: &[u32],
+        input: Slip10DeriveInput,
+        source: &Location,
+        output: &Location,
+    ) -> crate::Result<Zeroizing<Vec<u8>>> {
+        let raw = self
+            .0
+            .deriveSLIP10(
+                serde_wasm_bindgen::to_value(chain)?,
+                serde_wasm_bindgen::to_value(&input)?,
+                serde_wasm_bindgen::to_value(source)?,
+                serde_wasm_bindgen::to_value(output)?,
+            )
+            .await?;
+
+        Ok(take_secret(raw))
+    }
+
+    /// Gets the Ed25519 public key of the private key at `private_key`.
+    pub async fn get_ed25519_public_key(&self, private_key: &Location) -> crate::Result<Vec<u8>> {
+        let raw = self
+            .0
+            .getEd25519PublicKey(serde_wasm_bindgen::to_value(private_key)?)
+            .await?;
+
+        Ok(Uint8Array::new(&raw).to_vec())
+    }
+
+    /// Signs `msg` with the Ed25519 private key at `private_key`.
+    pub async fn sign_ed25519(&self, private_key: &Location, msg: &[u8]) -> crate::Result<Vec<u8>> {
+        let raw = self
+            .0
+            .signEd25519(
+                serde_wasm_bindgen::to_value(private_key)?,
+                serde_wasm_bindgen::to_value(msg)?,
+            )
+            .await?;
+
+        Ok(Uint8Array::new(&raw).to_vec())
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/stronghold.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Stronghold;
+        #[wasm_bindgen(catch, static_method_of = Stronghold)]
+        pub async fn load(path: &str, password: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn unload(this: &Stronghold) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn loadClient(this: &Stronghold, client: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn createClient(this: &Stronghold, client: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn save(this: &Stronghold) -> Result<(), JsValue>;
+    }
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/stronghold.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Client;
+        #[wasm_bindgen(method)]
+        pub fn getVault(this: &Client, name: &str) -> Vault;
+        #[wasm_bindgen(method)]
+        pub fn getStore(this: &Client) -> Store;
+    }
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/stronghold.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Store;
+        #[wasm_bindgen(method, catch)]
+        pub async fn get(this: &Store, key: &str) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn insert(
+            this: &Store,
+            key: &str,
+            value: JsValue,
+            lifetime: JsValue,
+        ) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn remove(this: &Store, key: &str) -> Result<JsValue, JsValue>;
+    }
 
-mod base {}
\ No newline at end of file
+    #[wasm_bindgen(module = "/src/scripts/plugins/stronghold.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Vault;
+        #[wasm_bindgen(method, getter)]
+        pub fn name(this: &Vault) -> String;
+        #[wasm_bindgen(method, catch)]
+        pub async fn insert(this: &Vault, recordPath: &str, secret: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn remove(this: &Vault, location: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn generateSLIP10Seed(
+            this: &Vault,
+            outputLocation: JsValue,
+            sizeBytes: Option<u32>,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn deriveSLIP10(
+            this: &Vault,
+            chain: JsValue,
+            source: JsValue,
+            sourceLocation: JsValue,
+            outputLocation: JsValue,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn getEd25519PublicKey(
+            this: &Vault,
+            privateKeyLocation: JsValue,
+        ) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn signEd25519(
+            this: &Vault,
+            privateKeyLocation: JsValue,
+            msg: JsValue,
+        ) -> Result<JsValue, JsValue>;
+    }
+}
@@ -1,7 +1,214 @@
-//! 
+//!
 //! Version: **upload-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-upload](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/upload)
 //!
+//! File paths relative to a [`BaseDirectory`] are resolved with [`resolve_in`](crate::api::path::resolve_in).
+//!
+
+pub use crate::api::path::BaseDirectory;
+
+use crate::Error;
+use futures::{
+    channel::{mpsc, oneshot},
+    Future, Stream,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use wasm_bindgen::{prelude::Closure, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Progress of an upload or download.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Progress {
+    /// Bytes transferred since the last progress event.
+    pub progress: u64,
+    /// Bytes transferred in total.
+    pub progress_total: u64,
+    /// Size of the transfer in bytes, `0` if the size is unknown.
+    pub total: u64,
+    /// Transfer speed in bytes per second.
+    pub transfer_speed: u64,
+}
+
+fn headers_to_value(headers: &[(&str, &str)]) -> crate::Result<JsValue> {
+    let headers: HashMap<&str, &str> = headers.iter().copied().collect();
+
+    Ok(headers.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+/// Starts a transfer in the background, keeping the progress closure alive until it settles.
+///
+/// Dropping the closure ends the progress stream, independently of the transfer future being polled.
+fn spawn_transfer(
+    file_path: PathBuf,
+    base_dir: Option<BaseDirectory>,
+    start: impl FnOnce(&str, &Closure<dyn FnMut(JsValue)>) -> Result<js_sys::Promise, JsValue>
+        + 'static,
+) -> (
+    oneshot::Receiver<crate::Result<JsValue>>,
+    mpsc::UnboundedReceiver<Progress>,
+) {
+    let (tx, rx) = mpsc::unbounded::<Progress>();
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+        if let Ok(progress) = serde_wasm_bindgen::from_value(raw) {
+            let _ = tx.unbounded_send(progress);
+        }
+    });
+
+    let (settled_tx, settled_rx) = oneshot::channel();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let settled: crate::Result<JsValue> = async {
+            let file_path = match base_dir {
+                Some(base_dir) => crate::api::path::resolve_in(base_dir, &file_path).await?,
+                None => file_path,
+            };
+            let Some(path) = file_path.to_str() else {
+                return Err(Error::Utf8(file_path));
+            };
+
+            Ok(JsFuture::from(start(path, &closure)?).await?)
+        }
+        .await;
+        drop(closure);
+
+        let _ = settled_tx.send(settled);
+    });
+
+    (settled_rx, rx)
+}
+
+/// Uploads the file at `file_path`, relative to `base_dir` if given, to `url` with a `POST` request.
+///
+/// Returns the future of the transfer, resolving to the response body, and a stream of its progress.
+/// The transfer runs in the background, the progress stream ends when it is done, even if the future is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::upload::{upload, BaseDirectory};
+/// use futures::StreamExt;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (transfer, mut progress) = upload(
+///     "https://example.com/upload",
+///     "report.pdf",
+///     Some(BaseDirectory::Document),
+///     &[],
+/// )?;
+///
+/// wasm_bindgen_futures::spawn_local(async move {
+///     while let Some(p) = progress.next().await {
+///         // update progress bar with p.progress_total / p.total
+///     }
+/// });
+///
+/// let response = transfer.await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`plugins > upload > upload`](https://beta.tauri.app/features/upload) to be enabled.
+pub fn upload(
+    url: &str,
+    file_path: impl AsRef<Path>,
+    base_dir: Option<BaseDirectory>,
+    headers: &[(&str, &str)],
+) -> crate::Result<(
+    impl Future<Output = crate::Result<String>>,
+    impl Stream<Item = Progress>,
+)> {
+    let url = url.to_owned();
+    let headers = headers_to_value(headers)?;
+
+    let file_path = file_path.as_ref().to_path_buf();
+    let (settled, rx) = spawn_transfer(file_path, base_dir, move |path, closure| {
+        base::upload(&url, path, closure, headers)
+    });
+    let transfer = async move { Ok(serde_wasm_bindgen::from_value(settled.await??)?) };
+
+    Ok((transfer, rx))
+}
+
+/// Downloads the file at `url` to `file_path`, relative to `base_dir` if given.
+///
+/// Returns the future of the transfer and a stream of its progress.
+/// The transfer runs in the background, the progress stream ends when it is done, even if the future is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::upload::{download, BaseDirectory};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (transfer, progress) = download(
+///     "https://example.com/asset.zip",
+///     "asset.zip",
+///     Some(BaseDirectory::Download),
+///     &[],
+///     None,
+/// )?;
+///
+/// transfer.await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`plugins > upload > download`](https://beta.tauri.app/features/upload) to be enabled.
+pub fn download(
+    url: &str,
+    file_path: impl AsRef<Path>,
+    base_dir: Option<BaseDirectory>,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> crate::Result<(
+    impl Future<Output = crate::Result<()>>,
+    impl Stream<Item = Progress>,
+)> {
+    let url = url.to_owned();
+    let headers = headers_to_value(headers)?;
+    let body = body.map(str::to_owned);
+
+    let file_path = file_path.as_ref().to_path_buf();
+    let (settled, rx) = spawn_transfer(file_path, base_dir, move |path, closure| {
+        base::download(&url, path, closure, headers, body.as_deref())
+    });
+    let transfer = async move {
+        settled.await??;
+
+        Ok(())
+    };
+
+    Ok((transfer, rx))
+}
+
+mod base {
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
 
-mod base {}
\ No newline at end of file
+    #[wasm_bindgen(module = "/src/scripts/plugins/upload.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub fn upload(
+            url: &str,
+            filePath: &str,
+            progressHandler: &Closure<dyn FnMut(JsValue)>,
+            headers: JsValue,
+        ) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub fn download(
+            url: &str,
+            filePath: &str,
+            progressHandler: &Closure<dyn FnMut(JsValue)>,
+            headers: JsValue,
+            body: Option<&str>,
+        ) -> Result<js_sys::Promise, JsValue>;
+    }
+}
@@ -1,7 +1,229 @@
-//! 
+//!
 //! Version: **websocket-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-websocket](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/websocket)
 //!
 
-mod base {}
\ No newline at end of file
+use futures::{channel::mpsc, Sink, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Options of a WebSocket connection.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionConfig {
+    /// Headers sent with the handshake request.
+    pub headers: Vec<(String, String)>,
+    /// The maximum size of an incoming message in bytes.
+    pub max_message_size: Option<usize>,
+    /// The maximum size of a single incoming frame in bytes.
+    pub max_frame_size: Option<usize>,
+    /// Read buffer capacity in bytes.
+    pub read_buffer_size: Option<usize>,
+    /// The target minimum size of the write buffer in bytes.
+    pub write_buffer_size: Option<usize>,
+    /// The max size of the write buffer in bytes.
+    pub max_write_buffer_size: Option<usize>,
+    /// Accept unmasked frames from the client, violates RFC 6455.
+    pub accept_unmasked_frames: Option<bool>,
+}
+
+/// The payload of a [`Message::Close`] frame.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloseFrame {
+    /// The close code, `1000` is a normal closure.
+    pub code: u16,
+    /// The reason of the closure.
+    pub reason: String,
+}
+
+/// A WebSocket message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(Option<CloseFrame>),
+}
+
+/// A WebSocket connection.
+///
+/// Incoming messages are read through [`Stream`], outgoing messages are written through [`Sink`].
+/// The stream ends after yielding the [`Message::Close`] received from the peer.
+/// The connection is closed when the handle is dropped.
+pub struct WebSocket {
+    ws: base::WebSocket,
+    rx: mpsc::UnboundedReceiver<crate::Result<Message>>,
+    unlisten: js_sys::Function,
+    pending: Option<JsFuture>,
+    closed: bool,
+    /// A close frame was received, the stream has ended.
+    finished: bool,
+    _listener: Closure<dyn FnMut(JsValue)>,
+}
+
+impl WebSocket {
+    /// Connects to `url`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::plugin::websocket::{ConnectionConfig, Message, WebSocket};
+    /// use futures::{SinkExt, StreamExt};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut ws = WebSocket::connect("wss://example.com", ConnectionConfig::default()).await?;
+    ///
+    /// ws.send(Message::Text("Hello".into())).await?;
+    ///
+    /// while let Some(message) = ws.next().await {
+    ///     let message = message?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires [`plugins > websocket > connect`](https://beta.tauri.app/features/websocket) to be enabled.
+    pub async fn connect(url: &str, config: ConnectionConfig) -> crate::Result<Self> {
+        let raw = base::WebSocket::connect(url, serde_wasm_bindgen::to_value(&config)?).await?;
+        let ws: base::WebSocket = raw.unchecked_into();
+
+        let (tx, rx) = mpsc::unbounded::<crate::Result<Message>>();
+
+        let listener = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+            let _ = tx.unbounded_send(serde_wasm_bindgen::from_value(raw).map_err(Into::into));
+        });
+        let unlisten = ws.addListener(&listener);
+
+        Ok(Self {
+            ws,
+            rx,
+            unlisten,
+            pending: None,
+            closed: false,
+            finished: false,
+            _listener: listener,
+        })
+    }
+
+    /// Closes the connection with a normal closure.
+    pub async fn disconnect(mut self) -> crate::Result<()> {
+        self.closed = true;
+        JsFuture::from(self.ws.disconnect()?).await?;
+
+        Ok(())
+    }
+
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<crate::Result<()>> {
+        let Some(pending) = self.pending.as_mut() else {
+            return Poll::Ready(Ok(()));
+        };
+
+        match Pin::new(pending).poll(cx) {
+            Poll::Ready(res) => {
+                self.pending = None;
+                Poll::Ready(res.map(|_| ()).map_err(Into::into))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for WebSocket {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&JsValue::NULL);
+
+        if !self.closed {
+            let _ = self.ws.disconnect();
+        }
+    }
+}
+
+impl Stream for WebSocket {
+    type Item = crate::Result<Message>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        let message = futures::ready!(self.rx.poll_next_unpin(cx));
+
+        if let Some(Ok(Message::Close(_))) = message {
+            // the connection is closed by the peer, there is nothing left to disconnect
+            self.closed = true;
+            self.finished = true;
+            self.rx.close();
+        }
+
+        Poll::Ready(message)
+    }
+}
+
+impl Sink<Message> for WebSocket {
+    type Error = crate::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<crate::Result<()>> {
+        self.poll_pending(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Message) -> crate::Result<()> {
+        if let Message::Close(_) = item {
+            self.closed = true;
+        }
+
+        let promise = self.ws.send(serde_wasm_bindgen::to_value(&item)?)?;
+        self.pending = Some(JsFuture::from(promise));
+
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<crate::Result<()>> {
+        self.poll_pending(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<crate::Result<()>> {
+        futures::ready!(self.poll_pending(cx))?;
+
+        if !self.closed {
+            self.closed = true;
+            let promise = self.ws.disconnect()?;
+            self.pending = Some(JsFuture::from(promise));
+        }
+
+        self.poll_pending(cx)
+    }
+}
+
+mod base {
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/websocket.js")]
+    extern "C" {
+        #[wasm_bindgen(js_name = default)]
+        #[derive(Debug, Clone)]
+        pub type WebSocket;
+        #[wasm_bindgen(catch, static_method_of = WebSocket, js_class = default)]
+        pub async fn connect(url: &str, config: JsValue) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method)]
+        pub fn addListener(
+            this: &WebSocket,
+            cb: &Closure<dyn FnMut(JsValue)>,
+        ) -> js_sys::Function;
+        #[wasm_bindgen(method, catch)]
+        pub fn send(this: &WebSocket, message: JsValue) -> Result<js_sys::Promise, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub fn disconnect(this: &WebSocket) -> Result<js_sys::Promise, JsValue>;
+    }
+}
@@ -1,7 +1,110 @@
-//! 
+//!
 //! Version: **window-state-v2.0.0-rc.0**
-//! 
+//!
 //! link to plugin: [tauri-plugin-window-state](https://github.com/tauri-apps/plugins-workspace/tree/v2/plugins/window-state)
 //!
 
-mod base {}
\ No newline at end of file
+bitflags::bitflags! {
+    /// The parts of a window's state to save or restore.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct StateFlags: u32 {
+        const SIZE = 1 << 0;
+        const POSITION = 1 << 1;
+        const MAXIMIZED = 1 << 2;
+        const VISIBLE = 1 << 3;
+        const DECORATIONS = 1 << 4;
+        const FULLSCREEN = 1 << 5;
+        const ALL = Self::SIZE.bits()
+            | Self::POSITION.bits()
+            | Self::MAXIMIZED.bits()
+            | Self::VISIBLE.bits()
+            | Self::DECORATIONS.bits()
+            | Self::FULLSCREEN.bits();
+    }
+}
+
+impl Default for StateFlags {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Saves the state of all open windows to disk.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::window_state::{save_window_state, StateFlags};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// save_window_state(StateFlags::SIZE | StateFlags::POSITION).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn save_window_state(flags: StateFlags) -> crate::Result<()> {
+    base::saveWindowState(flags.bits()).await?;
+
+    Ok(())
+}
+
+/// Restores the saved state of the window `label`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_wasm::plugin::window_state::{restore_state, StateFlags};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// restore_state("main", StateFlags::ALL).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub async fn restore_state(label: &str, flags: StateFlags) -> crate::Result<()> {
+    base::restoreState(label, flags.bits()).await?;
+
+    Ok(())
+}
+
+/// Gets the name of the file the window state is saved to.
+#[inline(always)]
+pub async fn filename() -> crate::Result<String> {
+    let raw = base::filename().await?;
+
+    Ok(serde_wasm_bindgen::from_value(raw)?)
+}
+
+#[cfg(feature = "window")]
+impl crate::api::window::WebviewWindow {
+    /// Restores the saved state of this window.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_wasm::api::window::current_window;
+    /// use tauri_wasm::plugin::window_state::StateFlags;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// current_window().restore_state(StateFlags::ALL).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn restore_state(&self, flags: StateFlags) -> crate::Result<()> {
+        restore_state(&self.label(), flags).await
+    }
+}
+
+mod base {
+    use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+    #[wasm_bindgen(module = "/src/scripts/plugins/window-state.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn saveWindowState(flags: u32) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn restoreState(label: &str, flags: u32) -> Result<(), JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn filename() -> Result<JsValue, JsValue>;
+    }
+}